@@ -1,82 +1,46 @@
-use std::{
-    collections::{HashMap, HashSet},
-    iter,
-};
-
-use advent_of_code::{coords, day_3::parse_hay_2, dims, parse_hay, Coord, MyMatch};
+use advent_of_code::grid::{Grid, Neighbourhood, Orientation, Spans};
 
 advent_of_code::solution!(3);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let dims = dims(input);
-    let mtchs = parse_hay(input);
-
-    let mut s_locs = HashSet::new();
-    for mtch in mtchs["symbol"].iter() {
-        s_locs.insert(coords(mtch.start, dims));
-    }
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
 
-    let mut total = 0;
-    for mtch in mtchs["num"].iter() {
-        for digit_idx in mtch.start..mtch.end {
-            let coord = coords(digit_idx, dims);
-            let adj = coord.adj();
-            let mut inter = adj.intersection(&s_locs);
-            println!("{} {:?}", mtch.string, inter);
+/// Numbers are written horizontally, so only digits in the same row belong together.
+fn numbers(grid: &Grid<char>) -> Spans<u64> {
+    grid.parse_spans(Orientation::Horizontal, char::is_ascii_digit)
+        .unwrap()
+}
 
-            if inter.next().is_some() {
-                total += u64::from_str_radix(&mtch.string, 10).unwrap();
-                break;
-            }
-        }
-    }
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid: Grid<char> = input.parse().ok()?;
+    let numbers = numbers(&grid);
+
+    let total = numbers
+        .iter()
+        .filter(|span| {
+            numbers
+                .border(span)
+                .iter()
+                .any(|point| is_symbol(grid[*point]))
+        })
+        .map(|span| span.value)
+        .sum();
 
     Some(total)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let dims = dims(input);
-    let mtchs = parse_hay_2(input);
-
-    let mut s_locs = HashSet::new();
-    for mtch in mtchs["symbol"].iter() {
-        s_locs.insert(coords(mtch.start, dims));
-    }
-
-    let mut gears: HashMap<Coord, Vec<MyMatch>> = HashMap::new();
-
-    let mut total = 0;
-    for mtch in mtchs["num"].iter() {
-        for digit_idx in mtch.start..mtch.end {
-            let coord = coords(digit_idx, dims);
-            let adj = coord.adj();
-            let mut inter = adj.intersection(&s_locs);
-            println!("{} {:?}", mtch.string, inter);
-
-            // number adjacents of a number might intersect with multiple gears
-            for gear in inter {
-                if let Some(existing_nums) = gears.get_mut(gear) {
-                    if !existing_nums.contains(mtch) {
-                        existing_nums.push(mtch.clone());
-                    }
-                } else {
-                    gears.insert(gear.clone(), vec![mtch.clone()]);
-                }
-            }
-
-            // if inter.next().is_some() {
-            //     total += u64::from_str_radix(&mtch.string, 10).unwrap();
-            //     break;
-            // }
-        }
-    }
-
-    for (gear, nums) in gears {
-        if nums.len() == 2 {
-            total += u64::from_str_radix(&nums[0].string, 10).unwrap()
-                * u64::from_str_radix(&nums[1].string, 10).unwrap()
-        }
-    }
+    let grid: Grid<char> = input.parse().ok()?;
+    let numbers = numbers(&grid);
+
+    let total = grid
+        .iter()
+        .filter(|(_, c)| **c == '*')
+        .map(|(point, _)| numbers.bordering(point, Neighbourhood::Eight))
+        .filter(|spans| spans.len() == 2)
+        .map(|spans| spans.iter().map(|span| span.value).product::<u64>())
+        .sum();
 
     Some(total)
 }
@@ -1,6 +1,16 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::datetime;
+use crate::Year;
+
+/// The last day of advent.
+const LAST_DAY: u8 = 25;
+
+/// Puzzles unlock at midnight EST, which is UTC-5.
+const UNLOCK_UTC_OFFSET_SECONDS: i64 = 5 * 3600;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
@@ -35,6 +45,31 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the unix timestamp at which the puzzle for this day unlocks in `year`.
+    pub fn unlock_timestamp(self, year: Year) -> i64 {
+        datetime::days_from_civil(i64::from(year.into_inner()), 12, u32::from(self.0))
+            * datetime::SECONDS_PER_DAY
+            + UNLOCK_UTC_OFFSET_SECONDS
+    }
+
+    /// Returns `true` if the puzzle for this day in `year` can be opened.
+    pub fn is_unlocked(self, year: Year) -> bool {
+        self.time_until_unlock(year).is_none()
+    }
+
+    /// Returns the time left until the puzzle for this day in `year` unlocks,
+    /// or [`None`] if it is unlocked already.
+    pub fn time_until_unlock(self, year: Year) -> Option<Duration> {
+        let remaining = self.unlock_timestamp(year) - datetime::now();
+        (remaining > 0).then(|| Duration::from_secs(remaining.unsigned_abs()))
+    }
+
+    /// Returns `false` for the last day of advent, which only has a single puzzle.
+    /// Its second star is awarded for collecting all others.
+    pub fn has_part_two(self) -> bool {
+        self.0 != LAST_DAY
+    }
 }
 
 impl Display for Day {
@@ -112,6 +147,11 @@ impl Iterator for AllDays {
     }
 }
 
+/// An iterator that yields every day of advent in `year` whose puzzle is unlocked.
+pub fn unlocked_days(year: Year) -> impl Iterator<Item = Day> {
+    all_days().take_while(move |day| day.is_unlocked(year))
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
@@ -134,7 +174,8 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, unlocked_days, Day};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +208,37 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        // 2023-12-01 05:00:00 UTC
+        assert_eq!(Day(1).unlock_timestamp(year!(2023)), 1_701_406_800);
+        // 2023-12-25 05:00:00 UTC
+        assert_eq!(Day(25).unlock_timestamp(year!(2023)), 1_703_480_400);
+        // 2015-12-01 05:00:00 UTC
+        assert_eq!(Day(1).unlock_timestamp(year!(2015)), 1_448_946_000);
+    }
+
+    #[test]
+    fn past_events_are_unlocked() {
+        assert!(Day(25).is_unlocked(year!(2015)));
+        assert_eq!(Day(25).time_until_unlock(year!(2015)), None);
+        assert_eq!(unlocked_days(year!(2015)).count(), 25);
+    }
+
+    #[test]
+    fn last_day_has_single_part() {
+        assert!(Day(1).has_part_two());
+        assert!(Day(24).has_part_two());
+        assert!(!Day(25).has_part_two());
+    }
+
+    #[test]
+    fn future_events_are_locked() {
+        assert!(!Day(1).is_unlocked(year!(9999)));
+        assert!(Day(1).time_until_unlock(year!(9999)).is_some());
+        assert_eq!(unlocked_days(year!(9999)).count(), 0);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,7 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use regex::{Captures, Regex};
 
+use crate::interval::{Interval, IntervalSet};
+
 pub fn parse_soil(hay: &str) -> (Vec<u64>, HashMap<String, Vec<MapItem>>) {
     let re = Regex::new(r"seeds: (?<seeds>.*)\n\nseed-to-soil map:\n(?<sts>(.*\n)*)\nsoil-to-fertilizer map:\n(?<stf>(.*\n)*)\nfertilizer-to-water map:\n(?<ftw>(.*\n)*)\nwater-to-light map:\n(?<wtl>(.*\n)*)\nlight-to-temperature map:\n(?<ltt>(.*\n)*)\ntemperature-to-humidity map:\n(?<tth>(.*\n)*)\nhumidity-to-location map:\n(?<htl>(.*|\n)*)").unwrap();
     let cap = re.captures(hay).unwrap();
@@ -96,17 +98,8 @@ pub fn range_mapper(source_range: Chunk, map: &Vec<MapItem>) -> Vec<Chunk> {
         }
     }
 
-    let mut untransformed = vec![source_range];
-    for exclude in transformed.iter() {
-        let mut new_untransformed = Vec::new();
-        for keep in untransformed.iter() {
-            if let Some(new_keep) = exclude.non_overlap(&keep) {
-                new_untransformed.extend(new_keep)
-            }
-        }
-        untransformed = new_untransformed;
-    }
-    res.extend(untransformed);
+    let transformed: IntervalSet<u64> = transformed.into_iter().collect();
+    res.extend(IntervalSet::from(source_range).difference(&transformed));
     res
 }
 
@@ -118,39 +111,17 @@ pub fn ranges_mapper(source_ranges: Vec<Chunk>, map: &Vec<MapItem>) -> Vec<Chunk
     res
 }
 
+/// Expands the seed ranges into the individual seeds, in ascending order and each listed once.
 pub fn seed_expand(seeds: Vec<u64>) -> Vec<u64> {
-    let mut res: Vec<Chunk> = Vec::new();
-    assert_eq!(seeds.len() % 2, 0);
-    let mut seeds_itr = seeds.iter();
-    for _ in 0..seeds.len() / 2 {
-        let start = seeds_itr.next().unwrap();
-        let range = seeds_itr.next().unwrap();
-        let end = start + range;
-        let mut candidate_chunks = HashSet::new();
-        candidate_chunks.insert(Chunk { start: *start, end });
-
-        // check for conflict with each chunk in the result built so far
-        for chunk in res.iter() {
-            // check all candidates (one to begin with, but it will become split up)
-            let mut next_candidates = HashSet::new();
-            for cand in candidate_chunks.into_iter() {
-                if let Some(n_o_chunks) = chunk.non_overlap(&cand) {
-                    for n_o_chunk in n_o_chunks {
-                        next_candidates.insert(n_o_chunk);
-                    }
-                }
-            }
-            candidate_chunks = next_candidates
-        }
-        // push non-overlapping candidates to result
-        for c in candidate_chunks {
-            res.push(c);
-        }
+    let mut res = Vec::new();
+    let mut seen: IntervalSet<u64> = IntervalSet::new();
+    // keeps the order of the input, skipping the seeds an earlier chunk already covered.
+    for chunk in seed_chunk_expand(seeds) {
+        let new = IntervalSet::from(chunk).difference(&seen);
+        res.extend(new.iter().flat_map(|c| c.start..c.end));
+        seen.insert(chunk);
     }
-    res.iter().fold(Vec::new(), |mut acc, c| {
-        acc.extend(c.to_u64s());
-        acc
-    })
+    res
 }
 
 pub fn seed_chunk_expand(seeds: Vec<u64>) -> Vec<Chunk> {
@@ -166,93 +137,8 @@ pub fn seed_chunk_expand(seeds: Vec<u64>) -> Vec<Chunk> {
     res
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Chunk {
-    pub start: u64,
-    // non-inclusive end
-    pub end: u64,
-}
-
-impl Chunk {
-    pub fn non_overlap(&self, other: &Chunk) -> Option<Vec<Chunk>> {
-        // other is subset
-        if other.start >= self.start && other.end <= self.end {
-            return None;
-        }
-        // other is superset
-        if other.start < self.start && other.end > self.end {
-            return Some(vec![
-                Chunk {
-                    start: other.start,
-                    end: self.start,
-                },
-                Chunk {
-                    start: self.end,
-                    end: other.end,
-                },
-            ]);
-        }
-        // other earlier start partial
-        if other.start < self.start && other.end > self.start {
-            return Some(vec![Chunk {
-                start: other.start,
-                end: self.start,
-            }]);
-        }
-        // other late end partial
-        if other.end > self.end && other.start < self.end {
-            return Some(vec![Chunk {
-                start: self.end,
-                end: other.end,
-            }]);
-        }
-        // no overlap
-        return Some(vec![Chunk {
-            start: other.start,
-            end: other.end,
-        }]);
-        panic!("overlapping case missed")
-    }
-
-    pub fn to_u64s(&self) -> Vec<u64> {
-        let mut res = Vec::new();
-        for idx in self.start..self.end {
-            res.push(idx);
-        }
-        res
-    }
-
-    pub fn intersection(&self, other: &Chunk) -> Option<Chunk> {
-        // no intersection
-        if other.end <= self.start || other.start >= self.end {
-            return None;
-        }
-
-        // full intersection
-        if other.start >= self.start && other.end <= self.end {
-            return Some(other.clone());
-        }
-
-        // over intersection
-        if other.start < self.start && other.end > self.end {
-            return Some(self.clone());
-        }
-
-        // partial other lower
-        if other.start < self.start {
-            return Some(Chunk {
-                start: self.start,
-                end: other.end,
-            });
-        } else {
-            //partial other higher
-            return Some(Chunk {
-                start: other.start,
-                end: self.end,
-            });
-        }
-    }
-}
+/// A range of seeds or mapped values, with a non-inclusive end.
+pub type Chunk = Interval<u64>;
 
 #[cfg(test)]
 mod tests {
@@ -310,11 +196,11 @@ humidity-to-location map:
     }
 
     #[test]
-    fn test_non_overlap_1() {
+    fn test_difference_1() {
         let chunk = Chunk { start: 4, end: 6 };
-        let mut other = Chunk { start: 2, end: 5 };
+        let other = Chunk { start: 2, end: 5 };
         assert_eq!(
-            chunk.non_overlap(&other).unwrap(),
+            other.difference(&chunk).into_iter().collect::<Vec<_>>(),
             vec![Chunk { start: 2, end: 4 }]
         );
         assert_eq!(
@@ -323,11 +209,11 @@ humidity-to-location map:
         );
     }
     #[test]
-    fn test_non_overlap_2() {
+    fn test_difference_2() {
         let chunk = Chunk { start: 4, end: 6 };
-        let mut other = Chunk { start: 2, end: 8 };
+        let other = Chunk { start: 2, end: 8 };
         assert_eq!(
-            chunk.non_overlap(&other).unwrap(),
+            other.difference(&chunk).into_iter().collect::<Vec<_>>(),
             vec![Chunk { start: 2, end: 4 }, Chunk { start: 6, end: 8 }]
         );
         assert_eq!(
@@ -336,21 +222,21 @@ humidity-to-location map:
         );
     }
     #[test]
-    fn test_non_overlap_3() {
+    fn test_difference_3() {
         let chunk = Chunk { start: 4, end: 6 };
-        let mut other = Chunk { start: 4, end: 6 };
-        assert_eq!(chunk.non_overlap(&other), None);
+        let other = Chunk { start: 4, end: 6 };
+        assert!(other.difference(&chunk).is_empty());
         assert_eq!(
             chunk.intersection(&other).unwrap(),
             Chunk { start: 4, end: 6 }
         );
     }
     #[test]
-    fn test_non_overlap_4() {
+    fn test_difference_4() {
         let chunk = Chunk { start: 4, end: 6 };
-        let mut other = Chunk { start: 5, end: 8 };
+        let other = Chunk { start: 5, end: 8 };
         assert_eq!(
-            chunk.non_overlap(&other).unwrap(),
+            other.difference(&chunk).into_iter().collect::<Vec<_>>(),
             vec![Chunk { start: 6, end: 8 }]
         );
         assert_eq!(
@@ -0,0 +1,242 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::{split_rows, Grid, GridParseError};
+
+impl<T> Grid<T> {
+    /// Parses a grid from puzzle text, mapping each character to a cell with `f`.
+    /// Fails with the position of the first character `f` rejects.
+    pub fn try_parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseCellsError> {
+        let (width, lines) = split_rows(input)?;
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (row, line) in lines.iter().enumerate() {
+            for (column, found) in line.chars().enumerate() {
+                let cell = f(found).ok_or(CellParseError { row, column, found })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self::from_cells(width, lines.len(), cells))
+    }
+
+    /// Parses a grid of a cell type that knows which characters it accepts,
+    /// e.g. one declared with [`cell_enum!`](crate::cell_enum).
+    ///
+    /// ```
+    /// # use advent_of_code::grid::Grid;
+    /// advent_of_code::cell_enum! {
+    ///     pub enum Tile {
+    ///         Open = '.',
+    ///         Wall = '#',
+    ///     }
+    /// }
+    ///
+    /// let grid = Grid::<Tile>::parse_cells(".#\n#.").unwrap();
+    /// assert_eq!(grid.to_string(), ".#\n#.");
+    /// let err = Grid::<Tile>::parse_cells("..\n.x").unwrap_err();
+    /// assert_eq!(err.to_string(), "unexpected character 'x' at row 2, column 2");
+    /// ```
+    pub fn parse_cells(input: &str) -> Result<Self, ParseCellsError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::try_parse(input, |c| T::try_from(c).ok())
+    }
+}
+
+/// An error which can be returned when a character does not map to a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellParseError {
+    pub row: usize,
+    pub column: usize,
+    pub found: char,
+}
+
+impl Error for CellParseError {}
+
+impl Display for CellParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unexpected character {:?} at row {}, column {}",
+            self.found,
+            self.row + 1,
+            self.column + 1
+        )
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`] of typed cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCellsError {
+    Shape(GridParseError),
+    Cell(CellParseError),
+}
+
+impl From<GridParseError> for ParseCellsError {
+    fn from(e: GridParseError) -> Self {
+        ParseCellsError::Shape(e)
+    }
+}
+
+impl From<CellParseError> for ParseCellsError {
+    fn from(e: CellParseError) -> Self {
+        ParseCellsError::Cell(e)
+    }
+}
+
+impl Error for ParseCellsError {}
+
+impl Display for ParseCellsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseCellsError::Shape(e) => write!(f, "{e}"),
+            ParseCellsError::Cell(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when converting a character that is not one of the
+/// cells declared with [`cell_enum!`](crate::cell_enum).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellFromCharError(pub char);
+
+impl Error for CellFromCharError {}
+
+impl Display for CellFromCharError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a known cell", self.0)
+    }
+}
+
+/// Declares an enum of grid cells along with the character of each variant.
+///
+/// The enum derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`, converts from
+/// a character with [`TryFrom<char>`] and back with [`From`], and displays as its character.
+/// Further attributes, e.g. doc comments or more derives, are passed on to the enum.
+///
+/// ```
+/// advent_of_code::cell_enum! {
+///     /// A tile of the pipe maze.
+///     pub enum Pipe {
+///         Vertical = '|',
+///         Horizontal = '-',
+///         Ground = '.',
+///     }
+/// }
+///
+/// assert_eq!(Pipe::try_from('|'), Ok(Pipe::Vertical));
+/// assert!(Pipe::try_from('x').is_err());
+/// assert_eq!(char::from(Pipe::Ground), '.');
+/// ```
+#[macro_export]
+macro_rules! cell_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $c:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl ::std::convert::TryFrom<char> for $name {
+            type Error = $crate::grid::CellFromCharError;
+
+            fn try_from(c: char) -> ::std::result::Result<Self, Self::Error> {
+                match c {
+                    $($c => Ok(Self::$variant),)+
+                    _ => Err($crate::grid::CellFromCharError(c)),
+                }
+            }
+        }
+
+        impl ::std::convert::From<$name> for char {
+            fn from(cell: $name) -> char {
+                match cell {
+                    $($name::$variant => $c),+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", char::from(*self))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CellFromCharError, CellParseError, ParseCellsError};
+    use crate::grid::{Grid, GridParseError, Point};
+
+    crate::cell_enum! {
+        enum Tile {
+            Open = '.',
+            Wall = '#',
+            /// The starting point.
+            Start = 'S',
+        }
+    }
+
+    #[test]
+    fn converts_cells() {
+        assert_eq!(Tile::try_from('S'), Ok(Tile::Start));
+        assert_eq!(Tile::try_from('?'), Err(CellFromCharError('?')));
+        assert_eq!(char::from(Tile::Wall), '#');
+        assert_eq!(Tile::Open.to_string(), ".");
+    }
+
+    #[test]
+    fn parses_typed_grids() {
+        let grid = Grid::<Tile>::parse_cells("S.#\n.##\r\n\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], Tile::Wall);
+        assert_eq!(grid.position(|t| *t == Tile::Start), Some(Point::new(0, 0)));
+        assert_eq!(grid.to_string(), "S.#\n.##");
+    }
+
+    #[test]
+    fn reports_positions() {
+        let err = Grid::<Tile>::parse_cells("S.#\n.#x").unwrap_err();
+        assert_eq!(
+            err,
+            ParseCellsError::Cell(CellParseError {
+                row: 1,
+                column: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "unexpected character 'x' at row 2, column 3"
+        );
+
+        let err = Grid::<Tile>::parse_cells("S.#\n.#").unwrap_err();
+        assert_eq!(
+            err,
+            ParseCellsError::Shape(GridParseError {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let digits = Grid::try_parse("12\n3a", |c| c.to_digit(10));
+        assert!(matches!(
+            digits,
+            Err(ParseCellsError::Cell(CellParseError { found: 'a', .. }))
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::{Add, AddAssign};
+
+use super::Point;
+
+/// One of the eight directions on a grid, with north pointing up (towards `y = 0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The four diagonal directions, clockwise from north east.
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::NorthEast => Point::new(1, -1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(0, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Returns `true` for the four diagonal directions.
+    pub fn is_diagonal(self) -> bool {
+        Self::DIAGONAL.contains(&self)
+    }
+
+    /// Rotates clockwise in steps of 45 degrees.
+    fn rotate(self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap();
+        Self::ALL[(index + eighths) % 8]
+    }
+
+    /// Turns 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_half_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_half_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turns around.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+    use crate::grid::Point;
+
+    #[test]
+    fn turns_and_reverses() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::SouthEast.reverse(), Direction::NorthWest);
+        assert_eq!(Direction::North.turn_half_right(), Direction::NorthEast);
+        assert_eq!(Direction::North.turn_half_left(), Direction::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.reverse().offset(),
+                Point::new(0, 0) - direction.offset()
+            );
+        }
+    }
+
+    #[test]
+    fn steps_in_a_direction() {
+        let mut point = Point::new(2, 2);
+        point += Direction::North;
+        assert_eq!(point, Point::new(2, 1));
+        assert_eq!(point + Direction::SouthWest, Point::new(1, 2));
+        assert!(Direction::SouthWest.is_diagonal());
+        assert!(!Direction::South.is_diagonal());
+    }
+}
@@ -0,0 +1,354 @@
+/// Module for puzzles that take place on a two dimensional grid of characters.
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+mod cells;
+mod direction;
+mod neighbours;
+mod paths;
+mod point;
+mod regions;
+mod render;
+mod spans;
+mod sparse;
+mod transform;
+pub use cells::*;
+pub use direction::*;
+pub use neighbours::*;
+pub use point::*;
+pub use regions::*;
+pub use render::*;
+pub use spans::*;
+pub use sparse::*;
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// # Parsing
+/// Each line of the input is a row, each character a cell. `\r\n` line endings and
+/// trailing blank lines are ignored, all other rows need to have the same length.
+///
+/// ```
+/// # use advent_of_code::grid::{Grid, Point};
+/// let grid = Grid::parse("#..\n.#.\r\n", |c| c == '#').unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid.get(Point::new(1, 1)), Some(&true));
+/// assert_eq!(grid.get(Point::new(3, 1)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` by `height` cells that all hold `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells in reading order.
+    fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        debug_assert_eq!(cells.len(), width * height);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from puzzle text, mapping each character to a cell with `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridParseError> {
+        let (width, lines) = split_rows(input)?;
+        let cells = lines.iter().flat_map(|line| line.chars()).map(&mut f);
+        Ok(Self::from_cells(width, lines.len(), cells.collect()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `true` if `point` lies on the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        let to_i64 = |n: usize| i64::try_from(n).unwrap();
+        Point::new(to_i64(index % self.width), to_i64(index / self.width))
+    }
+
+    /// Returns the cell at `point`, or [`None`] if it lies off the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at `point` mutably, or [`None`] if it lies off the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the cell at `point` and returns its previous value,
+    /// or [`None`] if the point lies off the grid, leaving the grid unchanged.
+    pub fn set(&mut self, point: Point, value: T) -> Option<T> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Returns row `y`, or [`None`] if it does not exist.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a size of zero.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over column `x` from top to bottom, or returns [`None`] if it does not exist.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// Iterates over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// Iterates over all points of the grid in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    /// Iterates over the cells along with their points in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Iterates mutably over the cells along with their points in reading order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(i, cell)| {
+            let to_i64 = |n: usize| i64::try_from(n).unwrap();
+            (Point::new(to_i64(i % width), to_i64(i / width)), cell)
+        })
+    }
+
+    /// Returns the point of the first cell in reading order that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_of(i))
+    }
+
+    /// Creates a grid of the same size by mapping each cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `point` lies off the grid, see [`Grid::get`] for a checked alternative.
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {point} lies off the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {point} lies off the grid"))
+    }
+}
+
+/// Displays the cells row by row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits puzzle text into rows and checks that they all have the same width.
+fn split_rows(input: &str) -> Result<(usize, Vec<&str>), GridParseError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    for (y, line) in lines.iter().enumerate() {
+        let len = line.chars().count();
+        if len != width {
+            return Err(GridParseError {
+                row: y,
+                expected: width,
+                found: len,
+            });
+        }
+    }
+
+    Ok((width, lines))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Grid<char> {
+    type Err = GridParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |c| c)
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`] with rows of differing length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridParseError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Error for GridParseError {}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells, expecting {} like the first row",
+            self.row + 1,
+            self.found,
+            self.expected
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, GridParseError, Point};
+
+    const EXAMPLE: &str = "467..114..\n...*......\n..35..633.\n";
+
+    #[test]
+    fn parses_dimensions() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        // unlike offsets into the raw string, the newline is not a column.
+        assert_eq!((grid.width(), grid.height()), (10, 3));
+        assert_eq!(grid[Point::new(3, 1)], '*');
+    }
+
+    #[test]
+    fn handles_line_endings_and_trailing_lines() {
+        let unix: Grid<char> = EXAMPLE.parse().unwrap();
+        let windows: Grid<char> = EXAMPLE.replace('\n', "\r\n").parse().unwrap();
+        let trailing: Grid<char> = format!("{EXAMPLE}\n  \n").parse().unwrap();
+        let no_newline: Grid<char> = EXAMPLE.trim_end().parse().unwrap();
+        assert_eq!(unix, windows);
+        assert_eq!(unix, trailing);
+        assert_eq!(unix, no_newline);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = "###\n##\n###".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err,
+            GridParseError {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "row 2 has 2 cells, expecting 3 like the first row"
+        );
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = Grid::new(2, 2, 0);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.set(Point::new(1, 1), 5), Some(0));
+        assert_eq!(grid.set(Point::new(2, 1), 5), None);
+        assert_eq!(grid[Point::new(1, 1)], 5);
+        assert!(grid.contains(Point::new(1, 0)));
+        assert!(!grid.contains(Point::new(1, -1)));
+    }
+
+    #[test]
+    fn accesses_rows_and_columns() {
+        let grid = Grid::parse("123\n456", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(2).unwrap().collect::<Vec<_>>(), [&3, &6]);
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn iterates_with_points() {
+        let mut grid = Grid::parse("ab\ncd", |c| c).unwrap();
+        let cells: Vec<(Point, char)> = grid.iter().map(|(p, c)| (p, *c)).collect();
+        assert_eq!(cells[0], (Point::new(0, 0), 'a'));
+        assert_eq!(cells[3], (Point::new(1, 1), 'd'));
+        assert_eq!(grid.position(|c| *c == 'c'), Some(Point::new(0, 1)));
+
+        for (point, cell) in grid.iter_mut() {
+            if point.x == 1 {
+                *cell = '#';
+            }
+        }
+        assert_eq!(grid.to_string(), "a#\nc#");
+    }
+
+    #[test]
+    fn orders_points_by_row() {
+        let mut points = vec![Point::new(1, 0), Point::new(0, 1), Point::new(0, 0)];
+        points.sort();
+        assert_eq!(
+            points,
+            [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::{Direction, Grid, Point};
+
+/// The offsets that count as neighbours of a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    /// The four cardinal neighbours.
+    Four,
+    /// The cardinal and the diagonal neighbours.
+    Eight,
+    /// Any other stencil, e.g. the moves of a knight.
+    Custom(&'a [Point]),
+}
+
+const FOUR: [Point; 4] = [
+    Direction::North.offset(),
+    Direction::East.offset(),
+    Direction::South.offset(),
+    Direction::West.offset(),
+];
+
+const EIGHT: [Point; 8] = [
+    Direction::North.offset(),
+    Direction::NorthEast.offset(),
+    Direction::East.offset(),
+    Direction::SouthEast.offset(),
+    Direction::South.offset(),
+    Direction::SouthWest.offset(),
+    Direction::West.offset(),
+    Direction::NorthWest.offset(),
+];
+
+impl<'a> Neighbourhood<'a> {
+    /// The offsets of the neighbours, clockwise from north for the built-in stencils.
+    pub fn offsets(self) -> &'a [Point] {
+        match self {
+            Self::Four => &FOUR,
+            Self::Eight => &EIGHT,
+            Self::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// How neighbours behave at the edges of an area of `width` by `height` points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Neighbours outside of the area are skipped.
+    Bounded { width: usize, height: usize },
+    /// Neighbours outside of the area wrap around to the opposite edge, like on a torus.
+    /// On areas smaller than the stencil, a point can be its own neighbour.
+    Wrapping { width: usize, height: usize },
+    /// Every neighbour is kept.
+    Unbounded,
+}
+
+impl Edges {
+    /// Applies the edges to `point`, returning [`None`] if it is not part of the area.
+    fn apply(self, point: Point) -> Option<Point> {
+        let to_i64 = |n: usize| i64::try_from(n).unwrap();
+
+        match self {
+            Self::Bounded { width, height } => {
+                let on_area =
+                    (0..to_i64(width)).contains(&point.x) && (0..to_i64(height)).contains(&point.y);
+                on_area.then_some(point)
+            }
+            Self::Wrapping { width, height } => (width > 0 && height > 0).then(|| {
+                Point::new(
+                    point.x.rem_euclid(to_i64(width)),
+                    point.y.rem_euclid(to_i64(height)),
+                )
+            }),
+            Self::Unbounded => Some(point),
+        }
+    }
+}
+
+impl Point {
+    /// Iterates over the neighbours of this point without allocating.
+    pub fn neighbours<'a>(
+        self,
+        neighbourhood: Neighbourhood<'a>,
+        edges: Edges,
+    ) -> impl Iterator<Item = Point> + 'a {
+        neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |offset| edges.apply(self + *offset))
+    }
+
+    /// The number of steps between two points when moving in the four cardinal directions.
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between two points when diagonal moves are allowed as well.
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl<T> Grid<T> {
+    /// The edges of this grid, for use with [`Point::neighbours`].
+    pub fn bounds(&self) -> Edges {
+        Edges::Bounded {
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+
+    /// Iterates over the neighbours of `point` that lie on the grid.
+    pub fn neighbours<'a>(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = Point> + 'a {
+        point.neighbours(neighbourhood, self.bounds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Edges, Neighbourhood};
+    use crate::grid::{Grid, Point};
+
+    const EDGES: Edges = Edges::Bounded {
+        width: 3,
+        height: 3,
+    };
+
+    #[test]
+    fn yields_neighbours_in_the_area() {
+        let corner = Point::new(0, 0);
+        let center = Point::new(1, 1);
+        assert_eq!(corner.neighbours(Neighbourhood::Four, EDGES).count(), 2);
+        assert_eq!(corner.neighbours(Neighbourhood::Eight, EDGES).count(), 3);
+        assert_eq!(center.neighbours(Neighbourhood::Four, EDGES).count(), 4);
+        assert_eq!(center.neighbours(Neighbourhood::Eight, EDGES).count(), 8);
+        assert_eq!(
+            corner
+                .neighbours(Neighbourhood::Four, Edges::Unbounded)
+                .collect::<Vec<_>>(),
+            [
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        let edges = Edges::Wrapping {
+            width: 3,
+            height: 3,
+        };
+        let neighbours: Vec<Point> = Point::new(0, 0)
+            .neighbours(Neighbourhood::Four, edges)
+            .collect();
+        assert_eq!(
+            neighbours,
+            [
+                Point::new(0, 2),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn supports_custom_stencils() {
+        let knight = [
+            Point::new(1, 2),
+            Point::new(2, 1),
+            Point::new(-1, 2),
+            Point::new(-2, 1),
+        ];
+        let grid = Grid::new(3, 3, ());
+        let moves: Vec<Point> = grid
+            .neighbours(Point::new(0, 0), Neighbourhood::Custom(&knight))
+            .collect();
+        assert_eq!(moves, [Point::new(1, 2), Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a.manhattan_distance(b), 11);
+        assert_eq!(a.chebyshev_distance(b), 7);
+        assert_eq!(a.manhattan_distance(a), 0);
+    }
+}
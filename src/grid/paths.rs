@@ -0,0 +1,128 @@
+use crate::search::{self, Cost, Search};
+
+use super::{Grid, Neighbourhood, Point};
+
+impl<T> Grid<T> {
+    /// Searches the fewest steps from `start` to `goal` through cells that match `passable`.
+    pub fn bfs(
+        &self,
+        start: Point,
+        goal: Point,
+        neighbourhood: Neighbourhood,
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Search<Point, usize> {
+        search::bfs(
+            start,
+            |point| {
+                self.neighbours(*point, neighbourhood)
+                    .filter(|next| passable(&self[*next]))
+                    .collect::<Vec<_>>()
+            },
+            |point| *point == goal,
+            None,
+        )
+    }
+
+    /// Searches the cheapest path from `start` to `goal`, where entering a cell costs `cost`
+    /// of the cell. Cells with a cost of [`None`] cannot be entered.
+    pub fn dijkstra<C: Cost>(
+        &self,
+        start: Point,
+        goal: Point,
+        neighbourhood: Neighbourhood,
+        mut cost: impl FnMut(&T) -> Option<C>,
+    ) -> Search<Point, C> {
+        search::dijkstra(
+            start,
+            |point| self.weighted_neighbours(*point, neighbourhood, &mut cost),
+            |point| *point == goal,
+            None,
+        )
+    }
+
+    /// Like [`Grid::dijkstra`], guided by the distance to `goal`.
+    /// Entering a cell has to cost at least one for the paths to be the cheapest.
+    pub fn astar<C: Cost + TryFrom<u64>>(
+        &self,
+        start: Point,
+        goal: Point,
+        neighbourhood: Neighbourhood,
+        mut cost: impl FnMut(&T) -> Option<C>,
+    ) -> Search<Point, C> {
+        let heuristic = |point: &Point| {
+            let distance = match neighbourhood {
+                Neighbourhood::Four => point.manhattan_distance(goal),
+                Neighbourhood::Eight => point.chebyshev_distance(goal),
+                // there is no estimate that holds for any stencil.
+                Neighbourhood::Custom(_) => 0,
+            };
+            C::try_from(distance).unwrap_or_default()
+        };
+
+        search::astar(
+            start,
+            |point| self.weighted_neighbours(*point, neighbourhood, &mut cost),
+            heuristic,
+            |point| *point == goal,
+            None,
+        )
+    }
+
+    fn weighted_neighbours<C>(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood,
+        cost: &mut impl FnMut(&T) -> Option<C>,
+    ) -> Vec<(Point, C)> {
+        self.neighbours(point, neighbourhood)
+            .filter_map(|next| Some((next, cost(&self[next])?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, Neighbourhood, Point};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#..G
+.##...#.
+";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let goal = grid.position(|c| *c == 'G').unwrap();
+        (grid, start, goal)
+    }
+
+    #[test]
+    fn walks_the_maze() {
+        let (grid, start, goal) = maze();
+        let search = grid.bfs(start, goal, Neighbourhood::Four, |c| *c != '#');
+        assert_eq!(search.goal_cost(), Some(11));
+        let path = search.path().unwrap();
+        assert!(path.iter().all(|point| grid[*point] != '#'));
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].manhattan_distance(step[1]) == 1));
+
+        let blocked = grid.bfs(start, goal, Neighbourhood::Four, |c| *c == '.');
+        assert_eq!(blocked.goal(), None);
+    }
+
+    #[test]
+    fn finds_the_cheapest_route() {
+        let grid = Grid::parse("11999\n19111\n11191", |c| c.to_digit(10)).unwrap();
+        let (start, goal) = (Point::new(0, 0), Point::new(4, 2));
+
+        let dijkstra = grid.dijkstra(start, goal, Neighbourhood::Four, |c| *c);
+        let astar = grid.astar(start, goal, Neighbourhood::Four, |c| *c);
+        assert_eq!(dijkstra.goal_cost(), Some(8));
+        assert_eq!(astar.goal_cost(), Some(8));
+        assert!(astar.visited().count() <= dijkstra.visited().count());
+        assert_eq!(astar.path(), dijkstra.path());
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use super::{Grid, Neighbourhood, Point};
+
+impl<T> Grid<T> {
+    /// Collects the points reachable from `start` through cells that match `predicate`,
+    /// in the order they are visited. Returns nothing if `start` itself does not match.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        neighbourhood: Neighbourhood,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Vec<Point> {
+        let mut visited = Grid::new(self.width(), self.height(), false);
+        self.fill_from(start, neighbourhood, &mut predicate, &mut visited)
+    }
+
+    fn fill_from(
+        &self,
+        start: Point,
+        neighbourhood: Neighbourhood,
+        predicate: &mut impl FnMut(&T) -> bool,
+        visited: &mut Grid<bool>,
+    ) -> Vec<Point> {
+        if !self.get(start).is_some_and(&mut *predicate) || visited[start] {
+            return vec![];
+        }
+
+        let mut points = vec![];
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+
+        while let Some(point) = queue.pop_front() {
+            points.push(point);
+            for next in self.neighbours(point, neighbourhood) {
+                if !visited[next] && predicate(&self[next]) {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        points
+    }
+
+    /// Labels the connected regions of cells that match `predicate`.
+    /// Regions are numbered in reading order of their first point.
+    pub fn components(
+        &self,
+        neighbourhood: Neighbourhood,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Components {
+        let mut visited = Grid::new(self.width(), self.height(), false);
+        let mut labels = Grid::new(self.width(), self.height(), None);
+        let mut regions = vec![];
+
+        for point in self.points() {
+            let mut region = self.fill_from(point, neighbourhood, &mut predicate, &mut visited);
+            if region.is_empty() {
+                continue;
+            }
+            region.sort();
+            for p in &region {
+                labels[*p] = Some(regions.len());
+            }
+            regions.push(region);
+        }
+
+        Components { labels, regions }
+    }
+}
+
+/// The connected regions of a grid, see [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Vec<Point>>,
+}
+
+impl Components {
+    /// The number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// The region that contains `point`, if any.
+    pub fn label(&self, point: Point) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    /// The points of region `id` in reading order.
+    ///
+    /// # Panics
+    /// Panics if there is no region `id`.
+    pub fn region(&self, id: usize) -> &[Point] {
+        &self.regions[id]
+    }
+
+    /// Iterates over the regions in order of their labels.
+    pub fn regions(&self) -> impl Iterator<Item = &[Point]> {
+        self.regions.iter().map(Vec::as_slice)
+    }
+
+    /// The points on the grid next to region `id` that are not part of it.
+    pub fn surroundings(&self, id: usize, neighbourhood: Neighbourhood) -> BTreeSet<Point> {
+        self.region(id)
+            .iter()
+            .flat_map(|point| self.labels.neighbours(*point, neighbourhood))
+            .filter(|point| self.label(*point) != Some(id))
+            .collect()
+    }
+
+    /// The regions next to `point`, not counting the region of `point` itself.
+    pub fn touching(&self, point: Point, neighbourhood: Neighbourhood) -> BTreeSet<usize> {
+        let own = self.label(point);
+        self.labels
+            .neighbours(point, neighbourhood)
+            .filter_map(|p| self.label(p))
+            .filter(|id| Some(*id) != own)
+            .collect()
+    }
+
+    /// The region adjacency graph: for each region, the regions it touches.
+    /// Regions of a single labelling only touch if `neighbourhood` is wider than
+    /// the one they were labelled with, e.g. diagonally.
+    pub fn adjacency(&self, neighbourhood: Neighbourhood) -> Vec<BTreeSet<usize>> {
+        (0..self.len())
+            .map(|id| {
+                self.region(id)
+                    .iter()
+                    .flat_map(|point| self.touching(*point, neighbourhood))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns `true` if region `id` does not reach the edge of the grid.
+    pub fn is_enclosed(&self, id: usize) -> bool {
+        let (width, height) = (self.labels.width(), self.labels.height());
+        let on_edge = |p: &Point| {
+            p.x == 0
+                || p.y == 0
+                || usize::try_from(p.x).is_ok_and(|x| x + 1 == width)
+                || usize::try_from(p.y).is_ok_and(|y| y + 1 == height)
+        };
+        !self.region(id).iter().any(on_edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::grid::{Grid, Neighbourhood, Point};
+
+    const MAP: &str = "\
+##..#
+#..##
+..#..
+##.#.
+";
+
+    fn grid() -> Grid<char> {
+        MAP.parse().unwrap()
+    }
+
+    #[test]
+    fn fills_from_a_point() {
+        let grid = grid();
+        let filled = grid.flood_fill(Point::new(2, 0), Neighbourhood::Four, |c| *c == '.');
+        assert_eq!(filled.len(), 6);
+        assert_eq!(filled[0], Point::new(2, 0));
+        assert!(filled.contains(&Point::new(1, 2)));
+        assert!(grid
+            .flood_fill(Point::new(0, 0), Neighbourhood::Four, |c| *c == '.')
+            .is_empty());
+        assert!(grid
+            .flood_fill(Point::new(-1, 0), Neighbourhood::Four, |_| true)
+            .is_empty());
+    }
+
+    #[test]
+    fn labels_components() {
+        let grid = grid();
+        let walls = grid.components(Neighbourhood::Four, |c| *c == '#');
+        assert_eq!(walls.len(), 5);
+        assert_eq!(
+            walls.region(0),
+            [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(walls.label(Point::new(3, 1)), Some(1));
+        assert_eq!(walls.label(Point::new(2, 0)), None);
+
+        let diagonal = grid.components(Neighbourhood::Eight, |c| *c == '#');
+        assert_eq!(diagonal.len(), 2);
+    }
+
+    #[test]
+    fn builds_the_adjacency_graph() {
+        let grid = grid();
+        let walls = grid.components(Neighbourhood::Four, |c| *c == '#');
+        let graph = walls.adjacency(Neighbourhood::Eight);
+        assert_eq!(graph[1], BTreeSet::from([2]));
+        assert_eq!(graph[2], BTreeSet::from([1, 3, 4]));
+        assert!(graph[0].is_empty());
+        assert_eq!(
+            walls.touching(Point::new(2, 1), Neighbourhood::Eight),
+            BTreeSet::from([0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn finds_surroundings_and_enclosed_regions() {
+        let grid: Grid<char> = "#####\n#..##\n#####".parse().unwrap();
+        let floor = grid.components(Neighbourhood::Four, |c| *c == '.');
+        assert!(floor.is_enclosed(0));
+        assert_eq!(floor.surroundings(0, Neighbourhood::Four).len(), 6);
+        assert_eq!(floor.surroundings(0, Neighbourhood::Eight).len(), 10);
+
+        let walls = grid.components(Neighbourhood::Four, |c| *c == '#');
+        assert!(!walls.is_enclosed(0));
+    }
+}
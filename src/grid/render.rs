@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+use super::{Grid, Point};
+
+/// A foreground colour of the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi(self) -> &'static str {
+        match self {
+            Self::Red => "\x1b[31m",
+            Self::Green => "\x1b[32m",
+            Self::Yellow => "\x1b[33m",
+            Self::Blue => "\x1b[34m",
+            Self::Magenta => "\x1b[35m",
+            Self::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+/// Cells to highlight when rendering a grid to the terminal.
+/// Later highlights of the same point replace earlier ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Highlights {
+    colours: HashMap<Point, Colour>,
+}
+
+impl Highlights {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn point(mut self, point: Point, colour: Colour) -> Self {
+        self.colours.insert(point, colour);
+        self
+    }
+
+    #[must_use]
+    pub fn points(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        self.colours
+            .extend(points.into_iter().map(|point| (point, colour)));
+        self
+    }
+
+    /// Highlights `len` points of a row, starting at `start`.
+    #[must_use]
+    pub fn span(self, start: Point, len: usize, colour: Colour) -> Self {
+        let len = i64::try_from(len).unwrap();
+        self.points((0..len).map(|dx| Point::new(start.x + dx, start.y)), colour)
+    }
+}
+
+impl<T: Display> Grid<T> {
+    /// Renders the grid like [`Display`] does, with highlighted cells in bold and colour.
+    pub fn to_ansi(&self, highlights: &Highlights) -> String {
+        let mut s = String::new();
+        for (point, cell) in self.iter() {
+            if point.x == 0 && point.y > 0 {
+                s.push('\n');
+            }
+            let _ = match highlights.colours.get(&point) {
+                Some(colour) => write!(s, "{ANSI_BOLD}{}{cell}{ANSI_RESET}", colour.ansi()),
+                None => write!(s, "{cell}"),
+            };
+        }
+        s
+    }
+
+    /// Prints the grid to the terminal with highlighted cells.
+    pub fn print_highlighted(&self, highlights: &Highlights) {
+        println!("{}", self.to_ansi(highlights));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An RGB colour of an [`Image`].
+pub type Rgb = [u8; 3];
+
+/// A picture of a grid, with each cell drawn as a square of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    /// Draws each cell as a square of `scale` by `scale` pixels of the colour `f` picks.
+    pub fn to_image(&self, scale: usize, mut f: impl FnMut(Point, &T) -> Rgb) -> Image {
+        let scale = scale.max(1);
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut pixels = vec![[0; 3]; width * height];
+
+        for (point, cell) in self.iter() {
+            let colour = f(point, cell);
+            let x = usize::try_from(point.x).unwrap() * scale;
+            let y = usize::try_from(point.y).unwrap() * scale;
+            for row in y..y + scale {
+                pixels[row * width + x..row * width + x + scale].fill(colour);
+            }
+        }
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Encodes the image as binary PPM, which most image viewers and `ffmpeg` understand.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    /// Encodes the image as PNG. The pixel data is stored without compression.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = vec![];
+        header.extend(u32::try_from(self.width).unwrap().to_be_bytes());
+        header.extend(u32::try_from(self.height).unwrap().to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filter and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        write_chunk(&mut png, b"IHDR", &header);
+
+        // each row starts with its filter type, which is none.
+        let mut scanlines = Vec::with_capacity(self.height * (1 + self.width * 3));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flatten());
+        }
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+
+    /// Writes the image to `path`, as PNG if it ends in `.png` and as PPM otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.to_png(),
+            _ => self.to_ppm(),
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, bytes)
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend(u32::try_from(data.len()).unwrap().to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65_535;
+
+    let mut out = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() {
+        vec![&[]]
+    } else {
+        data.chunks(MAX_BLOCK).collect()
+    };
+
+    for (i, block) in blocks.iter().enumerate() {
+        out.push(u8::from(i + 1 == blocks.len()));
+        let len = u16::try_from(block.len()).unwrap();
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(*block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
+
+/// Writes numbered frames into a folder, e.g. to turn them into an animation with
+/// `ffmpeg -i frame_%05d.png animation.gif`.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    dir: PathBuf,
+    extension: &'static str,
+    count: usize,
+}
+
+impl Frames {
+    /// Writes PNG frames into `dir`.
+    pub fn png(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            extension: "png",
+            count: 0,
+        }
+    }
+
+    /// Writes PPM frames into `dir`. Encoding is faster, but the files are larger.
+    pub fn ppm(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            extension: "ppm",
+            count: 0,
+        }
+    }
+
+    /// Writes the next frame and returns its path.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self
+            .dir
+            .join(format!("frame_{:05}.{}", self.count, self.extension));
+        image.save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// The number of frames written so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adler32, crc32, zlib_stored, Colour, Highlights};
+    use crate::grid::{Grid, Point};
+
+    #[test]
+    fn highlights_cells() {
+        let grid: Grid<char> = "467..\n...*.".parse().unwrap();
+        let highlights = Highlights::new()
+            .span(Point::new(0, 0), 3, Colour::Green)
+            .point(Point::new(3, 1), Colour::Red);
+        let ansi = grid.to_ansi(&highlights);
+        assert_eq!(
+            ansi,
+            "\x1b[1m\x1b[32m4\x1b[0m\x1b[1m\x1b[32m6\x1b[0m\x1b[1m\x1b[32m7\x1b[0m..\n...\x1b[1m\x1b[31m*\x1b[0m."
+        );
+        assert_eq!(grid.to_ansi(&Highlights::new()), grid.to_string());
+    }
+
+    #[test]
+    fn draws_images() {
+        let grid: Grid<char> = "#.\n..".parse().unwrap();
+        let image = grid.to_image(2, |_, c| if *c == '#' { [255, 0, 0] } else { [0; 3] });
+        assert_eq!((image.width(), image.height()), (4, 4));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        // the first two rows start with two red pixels
+        assert_eq!(&ppm[11..17], [255, 0, 0, 255, 0, 0]);
+        assert_eq!(&ppm[23..29], [255, 0, 0, 255, 0, 0]);
+        assert_eq!(&ppm[35..41], [0; 6]);
+    }
+
+    #[test]
+    fn encodes_png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+
+        let stored = zlib_stored(&[7; 70_000]);
+        // header, two blocks with a header of five bytes each, checksum
+        assert_eq!(stored.len(), 2 + 70_000 + 2 * 5 + 4);
+        assert_eq!(stored[2], 0);
+        assert_eq!(stored[2 + 5 + 65_535], 1);
+
+        let grid = Grid::new(3, 2, ());
+        let png = grid.to_image(1, |_, ()| [1, 2, 3]).to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use super::{Bounds, Grid, Neighbourhood, Point};
+
+/// The direction a [`Span`] runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Left to right along a row.
+    Horizontal,
+    /// Top to bottom along a column.
+    Vertical,
+}
+
+impl Orientation {
+    fn step(self) -> Point {
+        match self {
+            Self::Horizontal => Point::new(1, 0),
+            Self::Vertical => Point::new(0, 1),
+        }
+    }
+}
+
+/// A run of adjacent cells in a row or column, e.g. a number in a schematic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span<V> {
+    pub start: Point,
+    pub len: usize,
+    pub orientation: Orientation,
+    pub value: V,
+}
+
+impl<V> Span<V> {
+    /// The last point of the span.
+    pub fn end(&self) -> Point {
+        let offset = i64::try_from(self.len).unwrap() - 1;
+        let step = self.orientation.step();
+        Point::new(
+            self.start.x + step.x * offset,
+            self.start.y + step.y * offset,
+        )
+    }
+
+    /// Iterates over the points of the span from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (start, step) = (self.start, self.orientation.step());
+        (0..i64::try_from(self.len).unwrap())
+            .map(move |i| Point::new(start.x + step.x * i, start.y + step.y * i))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        Bounds::new(self.start, self.end()).contains(point)
+    }
+}
+
+/// The spans of a grid along with an index of the cells they cover, see [`Grid::spans`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spans<V> {
+    spans: Vec<Span<V>>,
+    index: Grid<Option<usize>>,
+}
+
+impl<V> Spans<V> {
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Iterates over the spans in reading order of their start.
+    pub fn iter(&self) -> impl Iterator<Item = &Span<V>> {
+        self.spans.iter()
+    }
+
+    /// The span that covers `point`, if any.
+    pub fn at(&self, point: Point) -> Option<&Span<V>> {
+        self.index
+            .get(point)
+            .copied()
+            .flatten()
+            .map(|id| &self.spans[id])
+    }
+
+    /// The spans next to `point`, each listed once, not counting a span that covers `point` itself.
+    pub fn bordering(&self, point: Point, neighbourhood: Neighbourhood) -> Vec<&Span<V>> {
+        let own = self.index.get(point).copied().flatten();
+        let ids: BTreeSet<usize> = self
+            .index
+            .neighbours(point, neighbourhood)
+            .filter_map(|p| self.index[p])
+            .filter(|id| Some(*id) != own)
+            .collect();
+        ids.into_iter().map(|id| &self.spans[id]).collect()
+    }
+
+    /// The points on the grid around `span`, including the diagonals, in reading order.
+    pub fn border(&self, span: &Span<V>) -> Vec<Point> {
+        let (start, end) = (span.start, span.end());
+        let around = Bounds::new(
+            Point::new(start.x - 1, start.y - 1),
+            Point::new(end.x + 1, end.y + 1),
+        );
+        around
+            .points()
+            .filter(|p| self.index.contains(*p) && !span.contains(*p))
+            .collect()
+    }
+
+    /// Transforms the values of the spans.
+    pub fn map<U>(self, mut f: impl FnMut(V) -> U) -> Spans<U> {
+        Spans {
+            spans: self
+                .spans
+                .into_iter()
+                .map(|span| Span {
+                    start: span.start,
+                    len: span.len,
+                    orientation: span.orientation,
+                    value: f(span.value),
+                })
+                .collect(),
+            index: self.index,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// The points of each row or column, depending on `orientation`.
+    fn lines(&self, orientation: Orientation) -> Vec<Vec<Point>> {
+        let to_i64 = |n: usize| i64::try_from(n).unwrap();
+        let (lines, len) = match orientation {
+            Orientation::Horizontal => (self.height(), self.width()),
+            Orientation::Vertical => (self.width(), self.height()),
+        };
+        (0..to_i64(lines))
+            .map(|line| {
+                (0..to_i64(len))
+                    .map(|i| match orientation {
+                        Orientation::Horizontal => Point::new(i, line),
+                        Orientation::Vertical => Point::new(line, i),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Extracts the maximal runs of cells that match `predicate` along rows or columns.
+    /// `value` turns the cells of each run into the value of its span.
+    pub fn spans<V>(
+        &self,
+        orientation: Orientation,
+        mut predicate: impl FnMut(&T) -> bool,
+        mut value: impl FnMut(&[&T]) -> V,
+    ) -> Spans<V> {
+        let mut runs = vec![];
+
+        for line in self.lines(orientation) {
+            let mut run: Vec<Point> = vec![];
+            for point in line.into_iter().map(Some).chain([None]) {
+                match point {
+                    Some(point) if predicate(&self[point]) => run.push(point),
+                    _ if !run.is_empty() => runs.push(std::mem::take(&mut run)),
+                    _ => {}
+                }
+            }
+        }
+
+        runs.sort_by_key(|run| run[0]);
+
+        let mut index = Grid::new(self.width(), self.height(), None);
+        let spans = runs
+            .into_iter()
+            .enumerate()
+            .map(|(id, run)| {
+                let cells: Vec<&T> = run.iter().map(|p| &self[*p]).collect();
+                for point in &run {
+                    index[*point] = Some(id);
+                }
+                Span {
+                    start: run[0],
+                    len: run.len(),
+                    orientation,
+                    value: value(&cells),
+                }
+            })
+            .collect();
+
+        Spans { spans, index }
+    }
+}
+
+impl Grid<char> {
+    /// Extracts the runs of characters that match `predicate` and parses them, e.g. numbers.
+    pub fn parse_spans<V: FromStr>(
+        &self,
+        orientation: Orientation,
+        predicate: impl FnMut(&char) -> bool,
+    ) -> Result<Spans<V>, V::Err> {
+        let spans = self.spans(orientation, predicate, |cells| {
+            cells.iter().copied().collect::<String>()
+        });
+
+        let mut parsed = Vec::with_capacity(spans.len());
+        for span in spans.spans {
+            parsed.push(Span {
+                start: span.start,
+                len: span.len,
+                orientation: span.orientation,
+                value: span.value.parse()?,
+            });
+        }
+
+        Ok(Spans {
+            spans: parsed,
+            index: spans.index,
+        })
+    }
+
+    /// Extracts the matches of `pattern` along rows or columns. Matches do not overlap
+    /// and empty matches are skipped.
+    pub fn find_spans(&self, orientation: Orientation, pattern: &Regex) -> Spans<String> {
+        let mut runs = vec![];
+
+        for line in self.lines(orientation) {
+            let text: String = line.iter().map(|p| self[*p]).collect();
+            // matches are reported as byte offsets, the line is indexed by character.
+            let char_at: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+            for m in pattern.find_iter(&text).filter(|m| !m.is_empty()) {
+                let first = char_at.binary_search(&m.start()).unwrap();
+                let len = m.as_str().chars().count();
+                runs.push((line[first], len, m.as_str().to_string()));
+            }
+        }
+
+        runs.sort_by_key(|(start, _, _)| *start);
+
+        let mut index = Grid::new(self.width(), self.height(), None);
+        let spans = runs
+            .into_iter()
+            .enumerate()
+            .map(|(id, (start, len, value))| {
+                let span = Span {
+                    start,
+                    len,
+                    orientation,
+                    value,
+                };
+                for point in span.points() {
+                    index[point] = Some(id);
+                }
+                span
+            })
+            .collect();
+
+        Spans { spans, index }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::{Orientation, Span};
+    use crate::grid::{Grid, Neighbourhood, Point};
+
+    const SCHEMATIC: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+";
+
+    fn grid() -> Grid<char> {
+        SCHEMATIC.parse().unwrap()
+    }
+
+    #[test]
+    fn extracts_numbers() {
+        let numbers = grid()
+            .parse_spans::<u32>(Orientation::Horizontal, char::is_ascii_digit)
+            .unwrap();
+        let values: Vec<u32> = numbers.iter().map(|span| span.value).collect();
+        assert_eq!(values, [467, 114, 35, 633]);
+
+        let span = numbers.at(Point::new(7, 2)).unwrap();
+        assert_eq!(
+            *span,
+            Span {
+                start: Point::new(6, 2),
+                len: 3,
+                orientation: Orientation::Horizontal,
+                value: 633
+            }
+        );
+        assert_eq!(span.end(), Point::new(8, 2));
+        assert_eq!(numbers.at(Point::new(3, 1)), None);
+    }
+
+    #[test]
+    fn extracts_vertical_runs() {
+        let grid: Grid<char> = "1.\n2.\n.3".parse().unwrap();
+        let numbers = grid
+            .parse_spans::<u32>(Orientation::Vertical, char::is_ascii_digit)
+            .unwrap();
+        let values: Vec<u32> = numbers.iter().map(|span| span.value).collect();
+        assert_eq!(values, [12, 3]);
+        assert_eq!(numbers.iter().next().unwrap().end(), Point::new(0, 1));
+    }
+
+    #[test]
+    fn finds_patterns() {
+        let spans = grid().find_spans(Orientation::Horizontal, &Regex::new(r"[^.\d]").unwrap());
+        let symbols: Vec<(Point, &str)> = spans
+            .iter()
+            .map(|span| (span.start, span.value.as_str()))
+            .collect();
+        assert_eq!(symbols, [(Point::new(3, 1), "*"), (Point::new(6, 3), "#")]);
+
+        let wide: Grid<char> = "ä1ö22".parse().unwrap();
+        let numbers = wide.find_spans(Orientation::Horizontal, &Regex::new(r"\d+").unwrap());
+        assert_eq!(numbers.iter().nth(1).unwrap().start, Point::new(3, 0));
+    }
+
+    #[test]
+    fn answers_border_queries() {
+        let grid = grid();
+        let numbers = grid
+            .parse_spans::<u32>(Orientation::Horizontal, char::is_ascii_digit)
+            .unwrap();
+
+        let gear: Vec<u32> = numbers
+            .bordering(Point::new(3, 1), Neighbourhood::Eight)
+            .iter()
+            .map(|span| span.value)
+            .collect();
+        assert_eq!(gear, [467, 35]);
+
+        let first = numbers.iter().next().unwrap();
+        let border = numbers.border(first);
+        assert_eq!(
+            border,
+            [
+                Point::new(3, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1),
+                Point::new(3, 1)
+            ]
+        );
+        assert!(border.iter().any(|p| grid[*p] == '*'));
+
+        let isolated = numbers.at(Point::new(5, 0)).unwrap();
+        assert!(numbers.border(isolated).iter().all(|p| grid[*p] == '.'));
+
+        let values = numbers.map(|value| value * 2);
+        assert_eq!(values.iter().next().unwrap().value, 934);
+    }
+}
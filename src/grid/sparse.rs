@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use super::{Grid, Point};
+
+/// A rectangle of points, with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// Creates the bounds spanned by two corners, in any order.
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        usize::try_from(self.max.x - self.min.x + 1).unwrap()
+    }
+
+    pub fn height(&self) -> usize {
+        usize::try_from(self.max.y - self.min.y + 1).unwrap()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grows the bounds to include `point`.
+    #[must_use]
+    pub fn include(self, point: Point) -> Self {
+        Self::new(
+            Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        )
+    }
+
+    /// Iterates over all points in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+/// A grid without a fixed size that only stores the cells that were set.
+/// All other cells hold the default value. Coordinates can be negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid where every cell holds `default`.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The cell at `point`, which is the default value unless it was set.
+    pub fn get(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Returns `true` if the cell at `point` was set.
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets the cell at `point` and returns its previous value, if it was set.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => Bounds::new(point, point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    /// Resets the cell at `point` to the default value and returns its previous value, if it was set.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+
+        // only points on the edge can shrink the bounds.
+        let on_edge = self.bounds.is_some_and(|b| {
+            point.x == b.min.x || point.x == b.max.x || point.y == b.min.y || point.y == b.max.y
+        });
+        if on_edge {
+            self.bounds = self.cells.keys().fold(None, |bounds, point| {
+                Some(bounds.map_or(Bounds::new(*point, *point), |b: Bounds| b.include(*point)))
+            });
+        }
+
+        Some(value)
+    }
+
+    /// The cell at `point` mutably, setting it to the default value first if it was not set.
+    pub fn get_mut(&mut self, point: Point) -> &mut T
+    where
+        T: Clone,
+    {
+        if !self.contains(point) {
+            self.insert(point, self.default.clone());
+        }
+        self.cells.get_mut(&point).unwrap()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// The number of cells that were set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest bounds that contain all cells that were set.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Iterates over the cells that were set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// Copies the cells within `window` into a dense grid,
+    /// with the top left corner of the window at `(0, 0)`.
+    pub fn crop(&self, window: Bounds) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = window.points().map(|p| self.get(p).clone()).collect();
+        Grid::from_cells(window.width(), window.height(), cells)
+    }
+
+    /// Copies all cells that were set into a dense grid, see [`SparseGrid::crop`].
+    /// Returns an empty grid if no cell was set.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        match self.bounds {
+            Some(bounds) => self.crop(bounds),
+            None => Grid::from_cells(0, 0, vec![]),
+        }
+    }
+
+    /// Copies the cells of a dense grid, leaving out the ones that hold `default`.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = Self::new(default);
+        for (point, cell) in grid.iter() {
+            if *cell != sparse.default {
+                sparse.insert(point, cell.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T: Display + Clone> SparseGrid<T> {
+    /// Renders the cells within `window`, row by row.
+    pub fn display_window(&self, window: Bounds) -> String {
+        self.crop(window).to_string()
+    }
+}
+
+/// Displays the cells within the bounds, row by row, without a trailing newline.
+impl<T: Display + Clone> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, SparseGrid};
+    use crate::grid::{Grid, Point};
+
+    #[test]
+    fn tracks_the_bounds() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(-2, 1), '#');
+        grid.insert(Point::new(3, -4), '#');
+        grid.insert(Point::new(0, 0), '#');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(-2, -4), Point::new(3, 1)))
+        );
+
+        grid.remove(Point::new(3, -4));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(-2, 0), Point::new(0, 1)))
+        );
+        grid.remove(Point::new(-2, 1));
+        grid.remove(Point::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn defaults_unset_cells() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(*grid.get(Point::new(-100, 100)), 0);
+        *grid.get_mut(Point::new(5, 5)) += 3;
+        *grid.get_mut(Point::new(5, 5)) += 3;
+        assert_eq!(*grid.get(Point::new(5, 5)), 6);
+        assert_eq!(grid.len(), 1);
+        assert!(!grid.contains(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn prints_cropped() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert(Point::new(-1, -1), '#');
+        grid.insert(Point::new(1, 0), '#');
+        assert_eq!(grid.to_string(), "#..\n..#");
+
+        let window = Bounds::new(Point::new(0, -2), Point::new(1, 0));
+        assert_eq!(grid.display_window(window), "..\n..\n.#");
+        assert_eq!(SparseGrid::new('.').to_string(), "");
+    }
+
+    #[test]
+    fn converts_from_and_to_dense_grids() {
+        let dense: Grid<char> = "..#\n#..".parse().unwrap();
+        let sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(*sparse.get(Point::new(2, 0)), '#');
+        assert_eq!(sparse.to_grid(), dense);
+
+        let mut sparse = sparse;
+        sparse.insert(Point::new(-1, 0), 'x');
+        assert_eq!(sparse.to_grid().to_string(), "x..#\n.#..");
+    }
+}
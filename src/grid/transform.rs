@@ -0,0 +1,130 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use super::{Grid, Point};
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of `width` by `height` cells, taking each cell from the point `source` maps it to.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(i64, i64) -> Point) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let to_i64 = |n: usize| i64::try_from(n).unwrap();
+                cells.push(self[source(to_i64(x), to_i64(y))].clone());
+            }
+        }
+        Self::from_cells(width, height, cells)
+    }
+
+    fn last_x(&self) -> i64 {
+        i64::try_from(self.width()).unwrap() - 1
+    }
+
+    fn last_y(&self) -> i64 {
+        i64::try_from(self.height()).unwrap() - 1
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height(), self.width(), |x, y| Point::new(y, x))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self {
+        let last_y = self.last_y();
+        self.remap(self.height(), self.width(), |x, y| {
+            Point::new(y, last_y - x)
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self {
+        let last_x = self.last_x();
+        self.remap(self.height(), self.width(), |x, y| {
+            Point::new(last_x - y, x)
+        })
+    }
+
+    /// Rotates the grid by 180 degrees.
+    #[must_use]
+    pub fn rotate_half(&self) -> Self {
+        let (last_x, last_y) = (self.last_x(), self.last_y());
+        self.remap(self.width(), self.height(), |x, y| {
+            Point::new(last_x - x, last_y - y)
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        let last_x = self.last_x();
+        self.remap(self.width(), self.height(), |x, y| {
+            Point::new(last_x - x, y)
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self {
+        let last_y = self.last_y();
+        self.remap(self.width(), self.height(), |x, y| {
+            Point::new(x, last_y - y)
+        })
+    }
+}
+
+impl<T: Hash> Grid<T> {
+    /// A hash of the size and contents of the grid, to remember states cheaply.
+    /// Different grids can share a hash, compare the grids themselves where that matters.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn transposes() {
+        assert_eq!(grid().transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid().transpose().transpose(), grid());
+    }
+
+    #[test]
+    fn rotates() {
+        assert_eq!(grid().rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid().rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid().rotate_half().to_string(), "fed\ncba");
+        assert_eq!(grid().rotate_right().rotate_left(), grid());
+        assert_eq!(grid().rotate_right().rotate_right(), grid().rotate_half());
+    }
+
+    #[test]
+    fn flips() {
+        assert_eq!(grid().flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid().flip_vertical().to_string(), "def\nabc");
+        assert_eq!(
+            grid().flip_horizontal().flip_vertical(),
+            grid().rotate_half()
+        );
+    }
+
+    #[test]
+    fn hashes_states() {
+        assert_eq!(grid().state_hash(), grid().state_hash());
+        assert_ne!(grid().state_hash(), grid().flip_vertical().state_hash());
+        // same cells, different shape
+        let row: Grid<char> = "abcdef".parse().unwrap();
+        assert_ne!(grid().state_hash(), row.state_hash());
+    }
+}
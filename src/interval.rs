@@ -0,0 +1,374 @@
+/// Module for half-open ranges of numbers and normalized sets of them.
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+
+/// The values from `start` up to, but not including, `end`.
+/// An interval whose end is not after its start is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns `true` if the intervals share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both intervals, or [`None`] if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The values of this interval that are not in `other`.
+    pub fn difference(&self, other: &Self) -> IntervalSet<T> {
+        IntervalSet::from(*self).difference(&IntervalSet::from(*other))
+    }
+
+    /// The number of values in the interval.
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Default,
+    {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+/// Displays the interval as `[start, end)`.
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of values stored as intervals that are sorted, non-empty and neither overlap
+/// nor touch, so that every set has exactly one representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the set holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Iterates over the intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // the first interval that ends after `value` is the only one that can contain it.
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    /// Adds the values of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The values in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    /// The values in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        let mut intervals = vec![];
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if let Some(overlap) = x.intersection(y) {
+                intervals.push(overlap);
+            }
+            // the interval that ends first cannot overlap anything after the other one.
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values in this set that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut others = other.intervals.iter().peekable();
+
+        for interval in &self.intervals {
+            let mut rest = *interval;
+            while let Some(cut) = others.peek() {
+                if cut.end <= rest.start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= rest.end {
+                    break;
+                }
+                if cut.start > rest.start {
+                    intervals.push(Interval::new(rest.start, cut.start));
+                }
+                rest.start = cut.end;
+                if cut.end > interval.end {
+                    // the cut may also reach into the next interval.
+                    break;
+                }
+                others.next();
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values within `bounds` that are not in the set.
+    #[must_use]
+    pub fn complement(&self, bounds: Interval<T>) -> Self {
+        IntervalSet::from(bounds).difference(self)
+    }
+
+    /// The smallest interval that contains all values, or [`None`] if the set is empty.
+    pub fn span(&self) -> Option<Interval<T>> {
+        let (first, last) = (self.intervals.first()?, self.intervals.last()?);
+        Some(Interval::new(first.start, last.end))
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Default,
+    {
+        self.intervals
+            .iter()
+            .fold(T::default(), |total, interval| total + interval.len())
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+/// Collects intervals in any order, merging them as needed.
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval<T>> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort_unstable();
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+
+        Self { intervals: merged }
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{Interval, IntervalSet};
+
+    /// The values the exhaustive tests draw from, small enough to try every combination.
+    const DOMAIN: u8 = 6;
+
+    /// Every interval within the domain, including empty ones.
+    fn intervals() -> Vec<Interval<u8>> {
+        (0..=DOMAIN)
+            .flat_map(|start| (0..=DOMAIN).map(move |end| Interval::new(start, end)))
+            .collect()
+    }
+
+    /// Every subset of the domain as a set of values and as an interval set.
+    fn sets() -> Vec<(BTreeSet<u8>, IntervalSet<u8>)> {
+        (0..1_u32 << DOMAIN)
+            .map(|mask| {
+                let values: BTreeSet<u8> = (0..DOMAIN).filter(|v| mask & (1 << v) != 0).collect();
+                let set = values.iter().map(|v| Interval::new(*v, v + 1)).collect();
+                (values, set)
+            })
+            .collect()
+    }
+
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.iter().flat_map(|i| i.start..i.end).collect()
+    }
+
+    fn assert_normalized(set: &IntervalSet<u8>) {
+        for interval in set.iter() {
+            assert!(!interval.is_empty(), "{set:?} holds an empty interval");
+        }
+        for pair in set.intervals.windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?} is not normalized");
+        }
+    }
+
+    #[test]
+    fn handles_single_intervals() {
+        let a = Interval::new(2, 5);
+        assert_eq!(a.len(), 3);
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert!(a.contains(2) && !a.contains(5));
+        assert_eq!(a.to_string(), "[2, 5)");
+        assert!(!a.overlaps(&Interval::new(5, 8)));
+
+        for a in intervals() {
+            for b in intervals() {
+                let (x, y) = (values(&a.into()), values(&b.into()));
+                let both: BTreeSet<u8> = x.intersection(&y).copied().collect();
+                let expected = (!both.is_empty())
+                    .then(|| Interval::new(*both.first().unwrap(), both.last().unwrap() + 1));
+                assert_eq!(a.intersection(&b), expected, "{a} & {b}");
+                assert_eq!(a.overlaps(&b), !both.is_empty());
+
+                let rest = a.difference(&b);
+                assert_normalized(&rest);
+                assert_eq!(values(&rest), &x - &y, "{a} - {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn merges_inserted_intervals() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(10, 20));
+        set.insert(Interval::new(30, 40));
+        set.insert(Interval::new(20, 25));
+        assert_eq!(set.iter().count(), 2);
+        set.insert(Interval::new(15, 35));
+        assert_eq!(set, IntervalSet::from(Interval::new(10, 40)));
+
+        for a in intervals() {
+            for b in intervals() {
+                for c in intervals() {
+                    let mut set = IntervalSet::new();
+                    for i in [a, b, c] {
+                        set.insert(i);
+                    }
+                    assert_normalized(&set);
+                    let expected = &(&values(&a.into()) | &values(&b.into())) | &values(&c.into());
+                    assert_eq!(values(&set), expected);
+                    assert_eq!(set, [c, a, b].into_iter().collect());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_a_brute_force_model() {
+        let sets = sets();
+        let bounds = Interval::new(1, DOMAIN - 1);
+        let within: BTreeSet<u8> = (bounds.start..bounds.end).collect();
+
+        for (x, a) in &sets {
+            assert_normalized(a);
+            assert_eq!(values(a), *x);
+            assert_eq!(usize::from(a.len()), x.len());
+            assert_eq!(a.is_empty(), x.is_empty());
+            for v in 0..=DOMAIN {
+                assert_eq!(a.contains(v), x.contains(&v));
+            }
+
+            let complement = a.complement(bounds);
+            assert_normalized(&complement);
+            assert_eq!(values(&complement), &within - x);
+
+            for (y, b) in &sets {
+                let checks = [
+                    (a.union(b), x | y),
+                    (a.intersection(b), x & y),
+                    (a.difference(b), x - y),
+                ];
+                for (set, expected) in checks {
+                    assert_normalized(&set);
+                    assert_eq!(values(&set), expected, "{x:?} and {y:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn measures_large_sets() {
+        let set: IntervalSet<u64> = [
+            Interval::new(0, 1_000_000_000_000),
+            Interval::new(2_000_000_000_000, 3_000_000_000_000),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2_000_000_000_000);
+        assert_eq!(set.span(), Some(Interval::new(0, 3_000_000_000_000)));
+        assert_eq!(IntervalSet::<u64>::new().span(), None);
+    }
+}
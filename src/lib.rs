@@ -1,40 +1,20 @@
 mod day;
+pub mod grid;
+pub mod interval;
+mod part;
+pub mod search;
 pub mod template;
+mod year;
 pub use day::*;
-use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use grid::{Edges, Neighbourhood, Point};
+pub use part::*;
+use std::collections::HashSet;
+pub use year::*;
 pub mod day_1;
 pub mod day_2;
-pub mod day_3;
 pub mod day_4;
 pub mod day_5;
 
-pub fn parse_hay(hay: &str) -> HashMap<&str, Vec<MyMatch>> {
-    let re = Regex::new(r"(?<num>\d+)|(?<symbol>[^0-9\n\.])").unwrap();
-    let mut mtchs = HashMap::new();
-    mtchs.insert("num", vec![]);
-    mtchs.insert("symbol", vec![]);
-    re.captures_iter(hay).for_each(|c| {
-        for name in ["num", "symbol"] {
-            if let Some(mtch) = c.name(name) {
-                mtchs.get_mut(name).unwrap().push(MyMatch {
-                    start: mtch.start(),
-                    end: mtch.end(),
-                    string: mtch.as_str().to_string(),
-                });
-            }
-        }
-    });
-    mtchs
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct MyMatch {
-    pub start: usize,
-    pub end: usize,
-    pub string: String,
-}
-
 pub fn dims(hay: &str) -> (usize, usize) {
     let chunks = hay.split_inclusive("\n").collect::<Vec<_>>();
     (chunks.len(), chunks[0].chars().count())
@@ -50,7 +30,7 @@ pub fn coords(i: usize, dims: (usize, usize)) -> Coord {
     }
 }
 
-/// zero indexed
+/// zero indexed, see [`grid::Point`] for coordinates that do not carry the grid dimensions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Coord {
     pub x: i64,
@@ -60,41 +40,118 @@ pub struct Coord {
 
 impl Coord {
     pub fn adj(&self) -> HashSet<Coord> {
-        let (x, y) = (self.x, self.y);
-        let mut set = HashSet::new();
-        for c in [
-            (x - 1, y - 1),
-            (x, y - 1),
-            (x + 1, y - 1),
-            (x - 1, y),
-            (x + 1, y),
-            (x - 1, y + 1),
-            (x, y + 1),
-            (x + 1, y + 1),
-        ] {
-            let coord_cand = Coord {
-                x: c.0,
-                y: c.1,
+        self.neighbours(Neighbourhood::Eight).collect()
+    }
+
+    /// Iterates over the neighbours that lie on the grid, without allocating.
+    pub fn neighbours<'a>(
+        &'a self,
+        neighbourhood: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        // dims are (rows, columns)
+        let edges = Edges::Bounded {
+            width: self.dims.1,
+            height: self.dims.0,
+        };
+        Point::new(self.x, self.y)
+            .neighbours(neighbourhood, edges)
+            .map(|p| Coord {
+                x: p.x,
+                y: p.y,
                 dims: self.dims,
-            };
-            if self.on_grid(&coord_cand) {
-                set.insert(coord_cand);
+            })
+    }
+
+    /// A path to `goal` with the fewest cardinal steps through coords that match `passable`,
+    /// both ends included.
+    pub fn shortest_path(
+        &self,
+        goal: &Coord,
+        mut passable: impl FnMut(&Coord) -> bool,
+    ) -> Option<Vec<Coord>> {
+        search::bfs(
+            self.clone(),
+            |coord| {
+                coord
+                    .neighbours(Neighbourhood::Four)
+                    .filter(|next| passable(next))
+                    .collect::<Vec<_>>()
+            },
+            |coord| coord == goal,
+            None,
+        )
+        .path()
+    }
+}
+
+/// A cycle in a sequence of states, e.g. a grid that is tilted over and over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    /// The number of steps until a state repeats.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Finds the cycle that the states reached by `step` from `initial` end up in,
+    /// using Brent's algorithm. Only two states are kept around at a time.
+    ///
+    /// Loops forever if the states never repeat.
+    pub fn find<S: PartialEq + Clone>(initial: &S, mut step: impl FnMut(&S) -> S) -> Self {
+        // find the length by letting the hare run ahead in increasing powers of two.
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = step(initial);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
             }
+            hare = step(&hare);
+            length += 1;
+        }
+
+        // find the start with the hare `length` steps ahead of the tortoise.
+        let mut tortoise = initial.clone();
+        let mut hare = initial.clone();
+        for _ in 0..length {
+            hare = step(&hare);
         }
-        set
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            start += 1;
+        }
+
+        Self { start, length }
     }
 
-    fn on_grid(&self, coord: &Coord) -> bool {
-        if coord.x < 0 || coord.y < 0 {
-            return false;
+    /// The earliest step that reaches the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
         }
-        // coords are 0-indexed, dims are 1-indexed
-        if coord.x + 1 > i64::try_from(self.dims.1).unwrap()
-            || coord.y + 1 > i64::try_from(self.dims.0).unwrap()
-        {
-            return false;
+    }
+
+    /// The state after `n` steps from `initial`, without taking more steps than needed
+    /// to find the cycle and to reach the equivalent step.
+    pub fn state_at<S: PartialEq + Clone>(
+        initial: &S,
+        mut step: impl FnMut(&S) -> S,
+        n: usize,
+    ) -> S {
+        let cycle = Self::find(initial, &mut step);
+        let mut state = initial.clone();
+        for _ in 0..cycle.equivalent_step(n) {
+            state = step(&state);
         }
-        true
+        state
     }
 }
 
@@ -102,16 +159,6 @@ impl Coord {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_frag() {
-        let subject = "16+....89\n16...#.89";
-        let map = parse_hay(subject);
-        // println!("{:?}", map["symbol"]);
-        assert_eq!(4, map["num"].len());
-        // test exclusion of \n char
-        assert_eq!(2, map["symbol"].len());
-    }
-
     #[test]
     fn test_dims() {
         let subject = "467..114..
@@ -192,5 +239,75 @@ mod tests {
         for c in adj.iter() {
             println!("{:?}", [c.x, c.y])
         }
+        assert_eq!(8, adj.len());
+
+        let corner = Coord {
+            x: 0,
+            y: 0,
+            dims: (3, 3),
+        };
+        assert_eq!(3, corner.adj().len());
+    }
+
+    #[test]
+    fn test_cycle() {
+        // powers of 3 repeat every 20 steps in the last two digits
+        let step = |n: &u32| n * 3 % 100;
+        assert_eq!(
+            Cycle {
+                start: 0,
+                length: 20
+            },
+            Cycle::find(&1, step)
+        );
+        // 101 leads into the same cycle after one step
+        let shifted = |n: &u32| if *n >= 100 { n - 100 } else { n * 3 % 100 };
+        assert_eq!(
+            Cycle {
+                start: 1,
+                length: 20
+            },
+            Cycle::find(&101, shifted)
+        );
+
+        let cycle = Cycle {
+            start: 3,
+            length: 4,
+        };
+        assert_eq!(2, cycle.equivalent_step(2));
+        assert_eq!(3, cycle.equivalent_step(7));
+        assert_eq!(6, cycle.equivalent_step(1_000_000_002));
+    }
+
+    #[test]
+    fn test_state_at() {
+        let step = |n: &u64| (n * n + 1) % 1009;
+        let mut expected = 5;
+        for _ in 0..10_000 {
+            expected = step(&expected);
+        }
+        assert_eq!(expected, Cycle::state_at(&5, step, 10_000));
+
+        let grid: grid::Grid<char> = "#.\n..".parse().unwrap();
+        let rotated = Cycle::state_at(&grid, grid::Grid::rotate_right, 1_000_000_001);
+        assert_eq!(grid.rotate_right(), rotated);
+        assert_eq!(
+            Cycle {
+                start: 0,
+                length: 4
+            },
+            Cycle::find(&grid, grid::Grid::rotate_right)
+        );
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let coord = |x, y| Coord { x, y, dims: (3, 4) };
+        // the coord at (1, 1) is blocked
+        let path = coord(0, 1)
+            .shortest_path(&coord(2, 1), |c| *c != coord(1, 1))
+            .unwrap();
+        assert_eq!(4, path.len() - 1);
+        assert!(!path.contains(&coord(1, 1)));
     }
 }
@@ -1,28 +1,59 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, doctor, download, leaderboard, read, scaffold, session, solve, stars, start,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::scaffold::AnswerType;
+    use advent_of_code::template::commands::session::Action;
+    use advent_of_code::{Day, Part, PuzzleId, Year};
 
     pub enum AppArguments {
+        Doctor,
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+            at_unlock: bool,
+        },
+        Leaderboard {
+            year: Year,
+            id: Option<u64>,
+            file: Option<String>,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+            current_part: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            answer_type: AnswerType,
+            with_lib: bool,
+        },
+        Start {
+            puzzle: PuzzleId,
+            answer_type: AnswerType,
+            with_lib: bool,
+        },
+        Session {
+            year: Year,
+            action: Action,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             time: bool,
-            submit: Option<u8>,
+            submit: Option<Part>,
+            update_readme: bool,
+        },
+        Stars {
+            year: Year,
+            id: Option<u64>,
+            file: Option<String>,
+            user: Option<u64>,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
         },
@@ -31,25 +62,69 @@ mod args {
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        // an invalid `AOC_YEAR` is reported up front instead of falling back to another year.
+        let configured = Year::try_configured().map_err(|e| format!("invalid AOC_YEAR: {e}"))?;
+        let year: Year = args.opt_value_from_str("--year")?.unwrap_or(configured);
+        let puzzle = |day: Day| PuzzleId::new(year, day);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
             },
+            Some("doctor") => AppArguments::Doctor,
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                at_unlock: args.contains("--at-unlock"),
+                puzzle: puzzle(args.free_from_str()?),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                year,
+                file: args.opt_value_from_str("--file")?,
+                id: args.opt_free_from_str()?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                current_part: args.contains("--current"),
+                puzzle: puzzle(args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                answer_type: args.opt_value_from_str("--type")?.unwrap_or_default(),
+                with_lib: args.contains("--lib"),
+                puzzle: puzzle(args.free_from_str()?),
+            },
+            Some("start") => AppArguments::Start {
+                answer_type: args.opt_value_from_str("--type")?.unwrap_or_default(),
+                with_lib: args.contains("--lib"),
+                puzzle: puzzle(args.free_from_str()?),
+            },
+            Some("session") => AppArguments::Session {
+                year,
+                action: match args.subcommand()?.as_deref() {
+                    Some("set") => Action::Set {
+                        global: args.contains("--global"),
+                        cookie: args.free_from_str()?,
+                    },
+                    Some("show") => Action::Show,
+                    Some("check") => Action::Check,
+                    _ => {
+                        eprintln!("Expected one of: set, show, check.");
+                        process::exit(1);
+                    }
+                },
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: puzzle(args.free_from_str()?),
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                update_readme: args.contains("--update-readme"),
+            },
+            Some("stars") => AppArguments::Stars {
+                year,
+                file: args.opt_value_from_str("--file")?,
+                user: args.opt_value_from_str("--user")?,
+                id: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +152,42 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+            } => all::handle(year, release, time),
+            AppArguments::Doctor => doctor::handle(),
+            AppArguments::Download { puzzle, at_unlock } => download::handle(puzzle, at_unlock),
+            AppArguments::Leaderboard { year, id, file } => leaderboard::handle(year, id, file),
+            AppArguments::Read {
+                puzzle,
+                current_part,
+            } => read::handle(puzzle, current_part),
+            AppArguments::Scaffold {
+                puzzle,
+                answer_type,
+                with_lib,
+            } => scaffold::handle(puzzle, answer_type, with_lib),
+            AppArguments::Start {
+                puzzle,
+                answer_type,
+                with_lib,
+            } => start::handle(puzzle, answer_type, with_lib),
+            AppArguments::Session { year, action } => session::handle(action, year),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                update_readme,
+            } => solve::handle(puzzle, release, time, submit, update_readme),
+            AppArguments::Stars {
+                year,
+                id,
+                file,
+                user,
+            } => stars::handle(year, id, file, user),
         },
     };
 }
@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// One of the two parts of a puzzle.
+///
+/// # Display
+/// This value displays as its number.
+///
+/// ```
+/// # use advent_of_code::Part;
+/// let part = Part::new(2).unwrap();
+/// assert_eq!(part.to_string(), "2")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Creates a [`Part`] from the provided value if it's either 1 or 2,
+    /// returns [`None`] otherwise.
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    /// Converts the [`Part`] into an [`u8`].
+    pub fn into_inner(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+impl PartialEq<u8> for Part {
+    fn eq(&self, other: &u8) -> bool {
+        self.into_inner().eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part = s.parse().map_err(|_| PartFromStrError)?;
+        Self::new(part).ok_or(PartFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug)]
+pub struct PartFromStrError;
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a part number of either 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Part;
+
+    #[test]
+    fn validates_parts() {
+        assert_eq!(Part::new(0), None);
+        assert_eq!(Part::new(1), Some(Part::One));
+        assert_eq!(Part::new(2), Some(Part::Two));
+        assert_eq!(Part::new(3), None);
+    }
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("7".parse::<Part>().is_err());
+        assert!("two".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn displays_parts() {
+        assert_eq!(Part::One.to_string(), "1");
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
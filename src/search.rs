@@ -0,0 +1,390 @@
+/// Module for shortest path searches over any kind of node.
+/// The graph is never built up front, nodes are discovered through a successor function.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cost of an edge or path. Costs must not be negative.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// The outcome of a search: the cost to reach each visited node and how it was reached.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    start: N,
+    goal: Option<N>,
+    costs: HashMap<N, C>,
+    /// All predecessors on a shortest path, the first one is the one that reached the node first.
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Search<N, C> {
+    fn new(start: N) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), C::default())]),
+            start,
+            goal: None,
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Records `parent -> node` with the path cost `cost`.
+    /// Returns `true` if the node was reached more cheaply than before.
+    fn relax(&mut self, parent: &N, node: N, cost: C) -> bool {
+        match self.costs.get(&node) {
+            Some(known) if cost > *known => false,
+            Some(known) if cost == *known => {
+                if node != self.start {
+                    let parents = self.parents.entry(node).or_default();
+                    if !parents.contains(parent) {
+                        parents.push(parent.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(node.clone(), cost);
+                self.parents.insert(node, vec![parent.clone()]);
+                true
+            }
+        }
+    }
+
+    /// The first goal that was reached, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of a shortest path to `node`, or [`None`] if it was not reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The cost of a shortest path to the goal.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal()?)
+    }
+
+    /// Returns `true` if the search reached `node`.
+    pub fn reached(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// Iterates over all nodes the search reached, in no particular order.
+    pub fn visited(&self) -> impl Iterator<Item = &N> {
+        self.costs.keys()
+    }
+
+    /// A shortest path from the start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.reached(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self
+            .parents
+            .get(path.last().unwrap())
+            .and_then(|p| p.first())
+        {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A shortest path from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal()?)
+    }
+
+    /// All shortest paths from the start to `node`, none of which visits a node twice.
+    /// Their number can grow exponentially with the size of open areas,
+    /// so prefer [`Search::on_shortest_paths`] where possible.
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.reached(node) {
+            return vec![];
+        }
+
+        let mut paths = vec![];
+        let mut stack = vec![vec![node.clone()]];
+        while let Some(path) = stack.pop() {
+            match self.parents.get(path.last().unwrap()) {
+                Some(parents) => {
+                    // edges of cost zero can form cycles of equally cheap parents.
+                    for parent in parents.iter().filter(|parent| !path.contains(parent)) {
+                        let mut next = path.clone();
+                        next.push(parent.clone());
+                        stack.push(next);
+                    }
+                }
+                None => {
+                    let mut path = path;
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+
+    /// All shortest paths from the start to the goal.
+    pub fn all_paths(&self) -> Vec<Vec<N>> {
+        self.goal()
+            .map(|goal| self.all_paths_to(goal))
+            .unwrap_or_default()
+    }
+
+    /// The nodes that lie on any shortest path to `node`, including the start and `node`.
+    pub fn on_shortest_paths(&self, node: &N) -> Vec<N> {
+        if !self.reached(node) {
+            return vec![];
+        }
+
+        let mut seen = vec![node.clone()];
+        let mut stack = vec![node.clone()];
+        while let Some(current) = stack.pop() {
+            for parent in self.parents.get(&current).into_iter().flatten() {
+                if !seen.contains(parent) {
+                    seen.push(parent.clone());
+                    stack.push(parent.clone());
+                }
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search from `start` until a node matching `is_goal` is found, every edge
+/// costing one step. Expands at most `limit` nodes if given.
+///
+/// The search continues until all shortest paths to the goal are known,
+/// so the result can be used with [`Search::all_paths`].
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    limit: Option<usize>,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    let mut expanded = 0;
+
+    while let Some(node) = queue.pop_front() {
+        let cost = search.costs[&node];
+        if search
+            .goal_cost()
+            .is_some_and(|goal_cost| cost >= goal_cost)
+        {
+            break;
+        }
+        if search.goal.is_none() && is_goal(&node) {
+            search.goal = Some(node);
+            continue;
+        }
+        if limit.is_some_and(|limit| expanded >= limit) {
+            break;
+        }
+        expanded += 1;
+
+        for next in successors(&node) {
+            if search.relax(&node, next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from `start` until a node matching `is_goal` is found.
+/// `successors` yields the neighbours of a node along with the cost of the edge.
+/// Expands at most `limit` nodes if given.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+    limit: Option<usize>,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal, limit)
+}
+
+/// A* search from `start` until a node matching `is_goal` is found.
+/// `heuristic` estimates the remaining cost to a goal. It must never overestimate it and
+/// must be consistent, i.e. not drop by more than the cost of an edge, to find shortest paths.
+/// Expands at most `limit` nodes if given.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    limit: Option<usize>,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // the heap holds indices into `nodes`, so nodes do not have to implement `Ord`.
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+    let mut search = Search::new(start);
+    let mut expanded = 0;
+
+    while let Some((Reverse(estimate), Reverse(cost), index)) = heap.pop() {
+        let node = nodes[index].clone();
+        if cost > search.costs[&node] {
+            // a cheaper path to this node was expanded already.
+            continue;
+        }
+        if search
+            .goal_cost()
+            .is_some_and(|goal_cost| estimate > goal_cost)
+        {
+            break;
+        }
+        if search.goal.is_none() && is_goal(&node) {
+            search.goal = Some(node);
+            continue;
+        }
+        // after the goal is found, keep going only to record its remaining equal cost parents.
+        if search
+            .goal_cost()
+            .is_some_and(|goal_cost| cost >= goal_cost)
+        {
+            continue;
+        }
+        if limit.is_some_and(|limit| expanded >= limit) {
+            break;
+        }
+        expanded += 1;
+
+        for (next, edge) in successors(&node) {
+            let next_cost = cost + edge;
+            if search.relax(&node, next.clone(), next_cost) {
+                heap.push((
+                    Reverse(next_cost + heuristic(&next)),
+                    Reverse(next_cost),
+                    nodes.len(),
+                ));
+                nodes.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+
+    /// A 3x3 lattice that can only be walked right and down.
+    fn successors(&(x, y): &(u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|(x, y)| *x <= 2 && *y <= 2)
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths_breadth_first() {
+        let search = bfs((0, 0), successors, |p| *p == (2, 2), None);
+        assert_eq!(search.goal(), Some(&(2, 2)));
+        assert_eq!(search.goal_cost(), Some(4));
+
+        let path = search.path().unwrap();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 2)));
+        assert_eq!(path.len(), 5);
+
+        assert_eq!(search.all_paths().len(), 6);
+        assert_eq!(search.all_paths_to(&(1, 1)).len(), 2);
+        assert_eq!(search.on_shortest_paths(&(1, 0)).len(), 2);
+    }
+
+    #[test]
+    fn respects_the_visit_limit() {
+        let search = bfs((0, 0), successors, |p| *p == (2, 2), Some(3));
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.path(), None);
+        assert!(search.reached(&(1, 1)));
+        assert!(!search.reached(&(2, 2)));
+    }
+
+    #[test]
+    fn explores_everything_without_a_goal() {
+        let search = bfs(0, |n: &u32| (*n < 5).then_some(n + 1), |_| false, None);
+        assert_eq!(search.visited().count(), 6);
+        assert_eq!(search.cost(&5), Some(5));
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(search.path_to(&6), None);
+        assert!(search.all_paths_to(&6).is_empty());
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // walking down is cheaper than walking right.
+        let weighted = |p: &(u8, u8)| {
+            successors(p)
+                .into_iter()
+                .map(|next| (next, if next.0 > p.0 { 5_u32 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let search = dijkstra((0, 0), weighted, |p| *p == (2, 2), None);
+        assert_eq!(search.goal_cost(), Some(12));
+        assert_eq!(search.all_paths().len(), 6);
+        assert_eq!(search.cost(&(0, 2)), Some(2));
+
+        let search = dijkstra((0, 0), weighted, |p| *p == (1, 0), None);
+        assert_eq!(search.path(), Some(vec![(0, 0), (1, 0)]));
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_a_heuristic() {
+        // stepping costs 1, jumping ahead three costs 2.
+        let moves = |n: &i32| vec![(n + 1, 1_u32), (n - 1, 1), (n + 3, 2)];
+        let goal: i32 = 13;
+        let heuristic = |n: &i32| (2 * goal.abs_diff(*n)).div_ceil(3);
+
+        let search = astar(0, moves, heuristic, |n| *n == goal, None);
+        let plain = dijkstra(0, moves, |n| *n == goal, None);
+        assert_eq!(search.goal_cost(), Some(9));
+        assert_eq!(plain.goal_cost(), Some(9));
+        assert!(search.visited().count() < plain.visited().count());
+        // four jumps and a step, in any order.
+        assert_eq!(search.all_paths().len(), 5);
+        assert_eq!(plain.all_paths().len(), 5);
+    }
+
+    #[test]
+    fn handles_free_cycles() {
+        // b and c can be walked back and forth for free.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 0_u32)],
+            'b' => vec![('c', 0), ('d', 1)],
+            'c' => vec![('b', 0), ('d', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra('a', edges, |n| *n == 'd', None);
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.path(), Some(vec!['a', 'b', 'd']));
+        assert_eq!(
+            search.all_paths(),
+            [vec!['a', 'b', 'c', 'd'], vec!['a', 'b', 'd']]
+        );
+        assert_eq!(search.all_paths_to(&'b'), [vec!['a', 'b']]);
+        assert_eq!(search.on_shortest_paths(&'d').len(), 4);
+    }
+}
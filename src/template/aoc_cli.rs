@@ -1,10 +1,13 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::{paths, session};
+use crate::{Part, PuzzleId};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,8 +38,9 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = paths::get_puzzle_path(puzzle);
+    create_parent_dir(&puzzle_path)?;
 
     let args = build_args(
         "read",
@@ -45,15 +49,17 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let input_path = paths::get_input_path(puzzle);
+    let puzzle_path = paths::get_puzzle_path(puzzle);
+    create_parent_dir(&input_path)?;
+    create_parent_dir(&puzzle_path)?;
 
     let args = build_args(
         "download",
@@ -64,7 +70,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
     let output = call_aoc_cli(&args)?;
@@ -74,38 +80,59 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
+/// Re-downloads the puzzle description only, e.g. to pick up part two after solving part one.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = paths::get_puzzle_path(puzzle);
+    create_parent_dir(&puzzle_path)?;
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path.to_string(),
+        ],
+        puzzle,
+    );
+
+    call_aoc_cli(&args)
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub fn submit(puzzle: PuzzleId, part: Part, result: &str) -> Result<Output, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], puzzle);
+    args.push(part.to_string());
+    args.push(result.to_string());
+    call_aoc_cli_captured(&args)
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// Puzzles of other years live in their own data directory, which might not exist yet.
+fn create_parent_dir(path: &str) -> Result<(), AocCommandError> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|_| AocCommandError::IoError)?;
     }
+    Ok(())
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    // aoc-cli only knows about the environment variable and the file in the home directory.
+    if let Some(session) = session::find() {
+        if let Some(path) = session.source.session_file() {
+            cmd_args.push("--session-file".into());
+            cmd_args.push(path.to_string_lossy().to_string());
+        }
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -125,3 +152,22 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Like [`call_aoc_cli`], but captures stdout so callers can inspect the response.
+/// The captured output is still forwarded to stdout.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
@@ -0,0 +1,95 @@
+/// Wrapper module around the "curl" command-line for endpoints that aoc-cli does not cover.
+use std::{
+    env,
+    fmt::Display,
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+use crate::template::session;
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/edchapman88/advent-of-code-2023 via curl";
+
+#[derive(Debug)]
+pub enum AocWebError {
+    CommandNotFound,
+    CommandNotCallable,
+    SessionNotFound,
+    BadExitStatus(Output),
+    BadResponse(String),
+}
+
+impl Display for AocWebError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocWebError::CommandNotFound => write!(f, "curl is not present in environment."),
+            AocWebError::CommandNotCallable => write!(f, "curl could not be called."),
+            AocWebError::SessionNotFound => write!(f, "could not find a session cookie."),
+            AocWebError::BadExitStatus(output) => write!(
+                f,
+                "curl exited with a non-zero status: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            AocWebError::BadResponse(reason) => write!(f, "unexpected response: {reason}"),
+        }
+    }
+}
+
+pub fn check() -> Result<(), AocWebError> {
+    Command::new("curl")
+        .arg("--version")
+        .output()
+        .map_err(|_| AocWebError::CommandNotFound)?;
+    Ok(())
+}
+
+/// The base url of the Advent of Code website.
+/// Can be overridden with the `AOC_URL` environment variable, e.g. to point at a local stand-in server.
+#[must_use]
+pub fn base_url() -> String {
+    match env::var("AOC_URL") {
+        Ok(url) if !url.is_empty() => url.trim_end_matches('/').to_string(),
+        _ => DEFAULT_URL.into(),
+    }
+}
+
+/// Fetches `path` relative to [`base_url`] with the session cookie attached and returns the response body.
+pub fn get(path: &str) -> Result<String, AocWebError> {
+    let session = session::find().ok_or(AocWebError::SessionNotFound)?;
+    let url = format!("{}/{}", base_url(), path.trim_start_matches('/'));
+
+    // pass the cookie via stdin so it does not show up in the process list.
+    let mut cmd = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--location",
+            "--user-agent",
+            USER_AGENT,
+            "--header",
+            "@-",
+            &url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| AocWebError::CommandNotCallable)?;
+
+    if let Some(mut stdin) = cmd.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session.cookie)
+            .map_err(|_| AocWebError::CommandNotCallable)?;
+    }
+
+    let output = cmd
+        .wait_with_output()
+        .map_err(|_| AocWebError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(AocWebError::BadExitStatus(output))
+    }
+}
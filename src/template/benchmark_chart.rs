@@ -0,0 +1,205 @@
+/// Module that renders benchmark timings as an SVG bar chart.
+/// Bars use a logarithmic scale, the timings of part 1 and part 2 are stacked per day.
+use std::fmt::Write;
+
+use crate::template::readme_benchmarks::{parse_nanos, Timings};
+
+// the palette of the Advent of Code website: its background, text and borders,
+// and the silver and gold of the stars for the two parts.
+const BACKGROUND: &str = "#0f0f23";
+const TEXT: &str = "#cccccc";
+const GRID: &str = "#333340";
+const PART_1: &str = "#9999cc";
+const PART_2: &str = "#ffff66";
+
+const BAR_WIDTH: f64 = 20.0;
+const BAR_GAP: f64 = 8.0;
+const PLOT_HEIGHT: f64 = 240.0;
+const MARGIN_LEFT: f64 = 60.0;
+const MARGIN_RIGHT: f64 = 16.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 32.0;
+/// Leaves room for the legend if only a few days were benchmarked.
+const MIN_WIDTH: f64 = 240.0;
+
+/// Formats a power of ten in nanoseconds as an axis label, e.g. `10µs`.
+fn format_decade(exponent: i32) -> String {
+    let units = ["ns", "µs", "ms", "s"];
+    let unit = usize::try_from(exponent.div_euclid(3))
+        .unwrap_or(0)
+        .min(units.len() - 1);
+    let value = 10_f64.powi(exponent - 3 * i32::try_from(unit).unwrap());
+    format!("{value}{}", units[unit])
+}
+
+/// The range of decades (powers of ten in nanoseconds) the chart covers.
+fn decades(timings: &[(f64, f64)]) -> (i32, i32) {
+    let values = timings
+        .iter()
+        .flat_map(|(part_1, part_2)| [*part_1, *part_2, part_1 + part_2])
+        .filter(|nanos| *nanos > 0.0);
+
+    let (min, max) = values.fold((f64::MAX, f64::MIN), |(min, max), nanos| {
+        (min.min(nanos), max.max(nanos))
+    });
+
+    if min > max {
+        return (0, 1);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (low, high) = (min.log10().floor() as i32, max.log10().ceil() as i32);
+    (low, high.max(low + 1))
+}
+
+/// Maps a duration to its height above the bottom of the plot.
+fn scale(nanos: f64, (low, high): (i32, i32)) -> f64 {
+    if nanos <= 0.0 {
+        return 0.0;
+    }
+    let position = (nanos.log10() - f64::from(low)) / f64::from(high - low);
+    position.clamp(0.0, 1.0) * PLOT_HEIGHT
+}
+
+/// Renders the timings as SVG. Returns [`None`] if there are no timings.
+#[must_use]
+pub fn render(timings: &[Timings]) -> Option<String> {
+    if timings.is_empty() {
+        return None;
+    }
+
+    let nanos = |timing: &Option<String>| timing.as_deref().and_then(parse_nanos).unwrap_or(0.0);
+    let parts: Vec<(f64, f64)> = timings
+        .iter()
+        .map(|t| (nanos(&t.part_1), nanos(&t.part_2)))
+        .collect();
+
+    let range = decades(&parts);
+    #[allow(clippy::cast_precision_loss)]
+    let width =
+        (MARGIN_LEFT + MARGIN_RIGHT + timings.len() as f64 * (BAR_WIDTH + BAR_GAP)).max(MIN_WIDTH);
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let baseline = MARGIN_TOP + PLOT_HEIGHT;
+
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{width}" height="{height}" fill="{BACKGROUND}"/>"#
+    );
+
+    // legend
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{MARGIN_LEFT}" y="12" width="10" height="10" fill="{PART_1}"/><text x="{}" y="21" fill="{TEXT}">Part 1</text>"#,
+        MARGIN_LEFT + 14.0
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="12" width="10" height="10" fill="{PART_2}"/><text x="{}" y="21" fill="{TEXT}">Part 2</text>"#,
+        MARGIN_LEFT + 70.0,
+        MARGIN_LEFT + 84.0
+    );
+
+    // grid lines at every decade
+    for exponent in range.0..=range.1 {
+        let y = baseline - scale(10_f64.powi(exponent), range);
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{MARGIN_LEFT}" y1="{y}" x2="{}" y2="{y}" stroke="{GRID}"/><text x="{}" y="{}" fill="{TEXT}" text-anchor="end">{}</text>"#,
+            width - MARGIN_RIGHT,
+            MARGIN_LEFT - 6.0,
+            y + 4.0,
+            format_decade(exponent)
+        );
+    }
+
+    for (i, (timing, (part_1, part_2))) in timings.iter().zip(&parts).enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = MARGIN_LEFT + BAR_GAP / 2.0 + i as f64 * (BAR_WIDTH + BAR_GAP);
+        let top_1 = scale(*part_1, range);
+        let top_2 = scale(part_1 + part_2, range);
+
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="{}" width="{BAR_WIDTH}" height="{top_1}" fill="{PART_1}"><title>Day {} part 1: {}</title></rect>"#,
+            baseline - top_1,
+            timing.day,
+            timing.part_1.as_deref().unwrap_or("-")
+        );
+        if *part_2 > 0.0 {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{}" width="{BAR_WIDTH}" height="{}" fill="{PART_2}"><title>Day {} part 2: {}</title></rect>"#,
+                baseline - top_2,
+                top_2 - top_1,
+                timing.day,
+                timing.part_2.as_deref().unwrap_or("-")
+            );
+        }
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" fill="{TEXT}" text-anchor="middle">{}</text>"#,
+            x + BAR_WIDTH / 2.0,
+            baseline + 16.0,
+            timing.day.into_inner()
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decades, format_decade, render, scale, PLOT_HEIGHT};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(4), "10µs");
+        assert_eq!(format_decade(6), "1ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let range = decades(&[(1_000.0, 0.0), (500_000.0, 500_000.0)]);
+        assert_eq!(range, (3, 6));
+        assert_eq!(scale(1_000.0, range), 0.0);
+        assert_eq!(scale(1_000_000.0, range), PLOT_HEIGHT);
+        assert!((scale(10_000.0, range) - PLOT_HEIGHT / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn renders_stacked_bars() {
+        let timings = vec![
+            Timings {
+                day: day!(1),
+                part_1: Some("10ms".into()),
+                part_2: Some("20ms".into()),
+                total_nanos: 3e+7,
+            },
+            Timings {
+                day: day!(25),
+                part_1: Some("50µs".into()),
+                part_2: None,
+                total_nanos: 5e+4,
+            },
+        ];
+        let svg = render(&timings).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<title>Day 01 part 1: 10ms</title>"));
+        assert!(svg.contains("<title>Day 01 part 2: 20ms</title>"));
+        assert!(svg.contains("<title>Day 25 part 1: 50µs</title>"));
+        assert!(!svg.contains("Day 25 part 2"));
+        assert_eq!(render(&[]), None);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::process;
+
+use regex::Regex;
+
+use crate::template::doctor::{self, Issue};
+use crate::template::{aoc_cli, paths, session, ANSI_BOLD, ANSI_RESET};
+use crate::{PuzzleId, Year};
+
+const DATA_FOLDERS: [&str; 3] = ["inputs", "examples", "puzzles"];
+
+pub fn handle() {
+    let solutions = find_solutions();
+
+    let mut issues = check_tools();
+    issues.extend(check_solutions(&solutions));
+    issues.extend(check_data_files(&solutions));
+    issues.extend(check_lib_modules());
+
+    if issues.is_empty() {
+        println!("🎄 No issues found.");
+        return;
+    }
+
+    for issue in &issues {
+        println!("{issue}");
+    }
+
+    println!("---");
+    println!("{ANSI_BOLD}Found {} issue(s).{ANSI_RESET}", issues.len());
+    process::exit(1);
+}
+
+/// Lists the file names in `dir`, or nothing if it does not exist.
+fn list_files(dir: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+fn find_solutions() -> Vec<PuzzleId> {
+    let mut solutions: Vec<PuzzleId> = list_files("src/bin")
+        .iter()
+        .filter_map(|name| paths::parse_bin_name(name.strip_suffix(".rs")?))
+        .collect();
+    solutions.sort();
+    solutions
+}
+
+fn check_tools() -> Vec<Issue> {
+    let mut issues = vec![];
+
+    if aoc_cli::check().is_err() {
+        issues.push(Issue::new(
+            "aoc-cli",
+            "command \"aoc\" not found or not callable",
+            "run `cargo install aoc-cli --version 0.12.0`",
+        ));
+    }
+
+    if session::find().is_none() {
+        issues.push(Issue::new(
+            "session",
+            "no session cookie found",
+            "run `cargo session set <cookie>`",
+        ));
+    }
+
+    issues
+}
+
+fn check_solutions(solutions: &[PuzzleId]) -> Vec<Issue> {
+    solutions
+        .iter()
+        .flat_map(|&puzzle| {
+            let read = |path: String| fs::read_to_string(path).ok();
+            let source = read(paths::get_path_for_bin(puzzle)).unwrap_or_default();
+            let example = read(paths::get_example_path(puzzle));
+            let input = read(paths::get_input_path(puzzle));
+            doctor::check_solution(puzzle, &source, example.as_deref(), input.as_deref())
+        })
+        .collect()
+}
+
+fn check_data_files(solutions: &[PuzzleId]) -> Vec<Issue> {
+    // data of other years might exist without any solution.
+    let mut years: BTreeSet<Year> = solutions.iter().map(|puzzle| puzzle.year).collect();
+    years.insert(Year::configured());
+    if let Ok(entries) = fs::read_dir("data") {
+        years.extend(
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().to_str()?.parse::<Year>().ok()),
+        );
+    }
+
+    let mut issues = vec![];
+
+    for year in years {
+        let year_solutions: Vec<PuzzleId> = solutions
+            .iter()
+            .copied()
+            .filter(|puzzle| puzzle.year == year)
+            .collect();
+
+        for folder in DATA_FOLDERS {
+            let dir = format!("{}/{folder}", paths::get_data_dir(year));
+            for name in list_files(&dir) {
+                let path = format!("{dir}/{name}");
+                issues.extend(doctor::check_data_file(
+                    &path,
+                    folder,
+                    &name,
+                    &year_solutions,
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn check_lib_modules() -> Vec<Issue> {
+    let module_re = Regex::new(r"^(y\d{4}_)?day_\d+$").unwrap();
+
+    let modules: Vec<String> = list_files("src")
+        .iter()
+        .filter_map(|name| name.strip_suffix(".rs"))
+        .filter(|module| module_re.is_match(module))
+        .map(String::from)
+        .collect();
+
+    if modules.is_empty() {
+        return vec![];
+    }
+
+    let lib = fs::read_to_string("src/lib.rs").unwrap_or_default();
+    doctor::check_lib_modules(&lib, &modules)
+}
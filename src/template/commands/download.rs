@@ -1,15 +1,98 @@
 use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::commands::session;
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{Day, PuzzleId, Year};
+use std::io::{stdout, Write};
 use std::process;
+use std::thread;
+use std::time::Duration;
 
-pub fn handle(day: Day) {
+/// Delays between download attempts after a puzzle unlocked, the server might not be ready yet.
+const RETRY_DELAYS_SECS: [u64; 6] = [2, 4, 8, 15, 30, 60];
+
+pub fn handle(puzzle: PuzzleId, at_unlock: bool) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if at_unlock {
+        download_at_unlock(puzzle);
+        return;
+    }
+
+    session::require_valid(puzzle.year);
+    if let Err(e) = aoc_cli::download(puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+/// Waits for `puzzle` to unlock and downloads it, retrying while the server is not ready.
+pub fn download_at_unlock(puzzle: PuzzleId) {
+    wait_for_unlock(puzzle.day, puzzle.year);
+    // the session is checked once the puzzle is out: the year's page does not exist before the
+    // event starts, and the session may have expired or been replaced during a long wait.
+    session::require_valid(puzzle.year);
+
+    let mut delays = RETRY_DELAYS_SECS.iter();
+
+    loop {
+        match aoc_cli::download(puzzle) {
+            Ok(_) => return,
+            Err(e) => {
+                let Some(delay) = delays.next() else {
+                    eprintln!("failed to call aoc-cli: {e}");
+                    process::exit(1);
+                };
+                eprintln!("Download failed ({e}), retrying in {delay}s...");
+                thread::sleep(Duration::from_secs(*delay));
+            }
+        }
+    }
+}
+
+/// Counts down until `day` unlocks.
+fn wait_for_unlock(day: Day, year: Year) {
+    let mut stdout = stdout();
+
+    while let Some(remaining) = day.time_until_unlock(year) {
+        print!(
+            "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+        thread::sleep(Duration::from_secs(1).min(remaining));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!                    ");
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (days, hours, minutes, seconds) = (
+        secs / 86_400,
+        (secs % 86_400) / 3600,
+        (secs % 3600) / 60,
+        secs % 60,
+    );
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_countdown;
+    use std::time::Duration;
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(5)), "00:00:05");
+        assert_eq!(format_countdown(Duration::from_secs(3725)), "01:02:05");
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
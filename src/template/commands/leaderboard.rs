@@ -0,0 +1,40 @@
+use std::process;
+
+use crate::template::{aoc_web, leaderboard};
+use crate::Year;
+
+pub fn handle(year: Year, id: Option<u64>, file: Option<String>) {
+    let Some(result) = load(year, id, file) else {
+        eprintln!("Expected a leaderboard id or a `--file`. Format: cargo leaderboard <id>");
+        process::exit(1);
+    };
+
+    match result {
+        Ok(leaderboard) => print!("{}", leaderboard::render(&leaderboard)),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the leaderboard from `file` if given, fetches it by `id` otherwise.
+/// Returns [`None`] if neither was provided.
+pub fn load(
+    year: Year,
+    id: Option<u64>,
+    file: Option<String>,
+) -> Option<Result<leaderboard::Leaderboard, leaderboard::Error>> {
+    if let Some(path) = file {
+        return Some(leaderboard::read(&path));
+    }
+
+    let id = id?;
+
+    if aoc_web::check().is_err() {
+        eprintln!("command \"curl\" not found or not callable. Install curl or pass a saved leaderboard via `--file`.");
+        process::exit(1);
+    }
+
+    Some(leaderboard::fetch(year, id))
+}
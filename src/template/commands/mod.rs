@@ -1,5 +1,10 @@
 pub mod all;
+pub mod doctor;
 pub mod download;
+pub mod leaderboard;
 pub mod read;
 pub mod scaffold;
+pub mod session;
 pub mod solve;
+pub mod stars;
+pub mod start;
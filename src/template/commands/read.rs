@@ -1,16 +1,48 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::{aoc_cli, paths, puzzle, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
+pub fn handle(puzzle: PuzzleId, current_part: bool) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if current_part {
+        read_current_part(puzzle);
+        return;
+    }
+
+    if let Err(e) = aoc_cli::read(puzzle) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    };
+}
+
+/// Fetches the latest description and prints only the part the user is working on.
+fn read_current_part(id: PuzzleId) {
+    if let Err(e) = aoc_cli::download_puzzle(id) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    let Some(puzzle) = puzzle::read(id) else {
+        eprintln!(
+            "Could not read puzzle description from \"{}\".",
+            paths::get_puzzle_path(id)
+        );
+        process::exit(1);
+    };
+
+    if let Some(title) = &puzzle.title {
+        println!(
+            "{ANSI_BOLD}Day {}: {title} (part {}){ANSI_RESET}",
+            id.day,
+            puzzle.current_part()
+        );
+        println!();
+    }
+
+    println!("{}", puzzle.current_description());
 }
@@ -1,18 +1,23 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    error::Error,
+    fmt::Display,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
     process,
+    str::FromStr,
 };
 
-use crate::Day;
+use crate::template::{paths, puzzle};
+use crate::PuzzleId;
 
 const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
@@ -34,42 +39,298 @@ mod tests {
 }
 "#;
 
+/// Day 25 only has a single part.
+const SINGLE_PART_MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+/// Library module for reusable code of a day, created with `--lib`.
+const LIB_MODULE_TEMPLATE: &str = r#"pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("a\nb"), vec!["a", "b"]);
+    }
+}
+"#;
+
+const LIB_PATH: &str = "src/lib.rs";
+
+/// Project templates take precedence over the built-in ones if present.
+const PROJECT_TEMPLATE_PATH: &str = "templates/solution.rs";
+const PROJECT_SINGLE_PART_TEMPLATE_PATH: &str = "templates/solution-single-part.rs";
+
+/// The return type of the generated `part_one` and `part_two` functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnswerType {
+    #[default]
+    U32,
+    U64,
+    I64,
+    String,
+}
+
+impl AnswerType {
+    fn as_str(self) -> &'static str {
+        match self {
+            AnswerType::U32 => "u32",
+            AnswerType::U64 => "u64",
+            AnswerType::I64 => "i64",
+            AnswerType::String => "String",
+        }
+    }
+}
+
+impl FromStr for AnswerType {
+    type Err = AnswerTypeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(AnswerType::U32),
+            "u64" => Ok(AnswerType::U64),
+            "i64" => Ok(AnswerType::I64),
+            "String" | "string" => Ok(AnswerType::String),
+            _ => Err(AnswerTypeFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`AnswerType`].
+#[derive(Debug)]
+pub struct AnswerTypeFromStrError;
+
+impl Error for AnswerTypeFromStrError {}
+
+impl Display for AnswerTypeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of u32, u64, i64, String")
+    }
+}
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create(true).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
+/// Reads the project template for `puzzle` if there is one, falls back to the built-in template otherwise.
+fn load_template(puzzle: PuzzleId) -> Result<(String, &'static str), std::io::Error> {
+    let (path, builtin) = if puzzle.day.has_part_two() {
+        (PROJECT_TEMPLATE_PATH, MODULE_TEMPLATE)
+    } else {
+        (
+            PROJECT_SINGLE_PART_TEMPLATE_PATH,
+            SINGLE_PART_MODULE_TEMPLATE,
+        )
+    };
+
+    if Path::new(path).exists() {
+        Ok((fs::read_to_string(path)?, path))
+    } else {
+        Ok((builtin.to_string(), "built-in template"))
+    }
+}
+
+/// Fills in the placeholders of a module template:
+///  - `DAY_NUMBER`: the day without padding, e.g. `7`.
+///  - `YEAR_NUMBER`: the year of the puzzle, e.g. `2023`.
+///  - `PUZZLE_TITLE`: the title of the puzzle if its description was downloaded, empty otherwise.
+///  - `ANSWER_TYPE`: the return type of the solution functions, e.g. `u64`.
+///
+/// Solutions for other years than the configured one pass their year to the `solution!` macro.
+fn render(
+    template: &str,
+    puzzle: PuzzleId,
+    title: Option<&str>,
+    answer_type: AnswerType,
+) -> String {
+    let template = if puzzle.year.is_configured() {
+        template.to_string()
+    } else {
+        template.replace(
+            "solution!(DAY_NUMBER)",
+            "solution!(DAY_NUMBER, year = YEAR_NUMBER)",
+        )
+    };
+
+    template
+        .replace("DAY_NUMBER", &puzzle.day.into_inner().to_string())
+        .replace("YEAR_NUMBER", &puzzle.year.to_string())
+        .replace("ANSWER_TYPE", answer_type.as_str())
+        // replaced last, so placeholders in the title are left alone.
+        .replace("PUZZLE_TITLE", title.unwrap_or_default())
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+/// Declares `module` in the contents of `lib.rs`, after the last existing module declaration.
+/// Returns [`None`] if the module is declared already.
+fn insert_module(lib: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {module};");
+
+    let is_declared = lib.lines().any(|line| {
+        let line = line.trim();
+        line == declaration || line == format!("mod {module};")
+    });
+    if is_declared {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let position = lines
+        .iter()
+        .rposition(|line| {
+            (line.starts_with("pub mod ") || line.starts_with("mod ")) && line.ends_with(';')
+        })
+        .map_or(0, |i| i + 1);
+    lines.insert(position, &declaration);
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Some(lib)
+}
+
+/// Creates the library module of `puzzle` unless it exists already and declares it in `lib.rs`.
+fn scaffold_lib_module(puzzle: PuzzleId) {
+    let module = paths::get_lib_module_name(puzzle);
+    let module_path = paths::get_path_for_lib_module(puzzle)
+        .trim_start_matches("./")
+        .to_string();
+
+    match safe_create_file(&module_path) {
+        Ok(mut file) => match file.write_all(LIB_MODULE_TEMPLATE.as_bytes()) {
+            Ok(()) => println!("Created library module \"{}\"", &module_path),
+            Err(e) => {
+                eprintln!("Failed to write library module contents: {e}");
+                process::exit(1);
+            }
+        },
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            println!("Library module \"{}\" exists already", &module_path);
+        }
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to create library module: {e}");
+            process::exit(1);
+        }
+    }
+
+    let lib = match fs::read_to_string(LIB_PATH) {
+        Ok(lib) => lib,
+        Err(e) => {
+            eprintln!("Failed to read \"{LIB_PATH}\": {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    match insert_module(&lib, &module) {
+        Some(lib) => match fs::write(LIB_PATH, lib) {
+            Ok(()) => println!("Declared `pub mod {module};` in \"{LIB_PATH}\""),
+            Err(e) => {
+                eprintln!("Failed to update \"{LIB_PATH}\": {e}");
+                process::exit(1);
+            }
+        },
+        None => println!("Module `{module}` is declared in \"{LIB_PATH}\" already"),
+    }
+}
+
+pub fn handle(puzzle: PuzzleId, answer_type: AnswerType, with_lib: bool) {
+    create(puzzle, answer_type, with_lib, false);
+
+    println!("---");
+    if puzzle.year.is_configured() {
+        println!("🎄 Type `cargo solve {}` to run your solution.", puzzle.day);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            puzzle.day, puzzle.year
+        );
+    }
+}
+
+/// Creates the files of `puzzle`. An existing module file is an error unless `skip_existing` is set,
+/// in which case it is left untouched. Existing input and example files are never truncated.
+pub fn create(puzzle: PuzzleId, answer_type: AnswerType, with_lib: bool, skip_existing: bool) {
+    let input_path = paths::get_input_path(puzzle);
+    let example_path = paths::get_example_path(puzzle);
+    let module_path = paths::get_path_for_bin(puzzle)
+        .trim_start_matches("./")
+        .to_string();
+
+    let (template, template_source) = match load_template(puzzle) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read module template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let title = puzzle::read(puzzle).and_then(|p| p.title);
+    let mut contents = render(&template, puzzle, title.as_deref(), answer_type);
+
+    if with_lib {
+        // the module has to exist before a bin that imports it, so a failure here leaves no broken bin behind.
+        scaffold_lib_module(puzzle);
+        let module = paths::get_lib_module_name(puzzle);
+        contents = format!("use advent_of_code::{module}::*;\n\n{contents}");
+    }
+
+    match safe_create_file(&module_path) {
+        Ok(mut file) => match file.write_all(contents.as_bytes()) {
+            Ok(()) => {
+                println!(
+                    "Created module file \"{}\" from {}",
+                    &module_path, template_source
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to write module contents: {e}");
+                process::exit(1);
+            }
+        },
+        Err(e) if skip_existing && e.kind() == ErrorKind::AlreadyExists => {
+            println!("Module file \"{}\" exists already", &module_path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to create module file: {e}");
             process::exit(1);
         }
     }
 
+    let input_exists = Path::new(&input_path).exists();
     match create_file(&input_path) {
+        Ok(_) if input_exists => {
+            println!("Input file \"{}\" exists already", &input_path);
+        }
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
@@ -79,7 +340,11 @@ pub fn handle(day: Day) {
         }
     }
 
+    let example_exists = Path::new(&example_path).exists();
     match create_file(&example_path) {
+        Ok(_) if example_exists => {
+            println!("Example file \"{}\" exists already", &example_path);
+        }
         Ok(_) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
@@ -88,7 +353,64 @@ pub fn handle(day: Day) {
             process::exit(1);
         }
     }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{insert_module, render, AnswerType, MODULE_TEMPLATE};
+    use crate::{day, year, PuzzleId, Year};
+
+    #[test]
+    fn parses_answer_types() {
+        assert_eq!("u64".parse::<AnswerType>().unwrap(), AnswerType::U64);
+        assert_eq!("i64".parse::<AnswerType>().unwrap(), AnswerType::I64);
+        assert_eq!("String".parse::<AnswerType>().unwrap(), AnswerType::String);
+        assert!("f32".parse::<AnswerType>().is_err());
+    }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    #[test]
+    fn renders_builtin_template() {
+        let puzzle = PuzzleId::new(Year::configured(), day!(7));
+        let module = render(MODULE_TEMPLATE, puzzle, None, AnswerType::U64);
+        assert!(module.starts_with("advent_of_code::solution!(7);"));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u64> {"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<u64> {"));
+    }
+
+    #[test]
+    fn renders_year_of_other_events() {
+        let puzzle = PuzzleId::new(year!(2015), day!(7));
+        let module = render(MODULE_TEMPLATE, puzzle, None, AnswerType::U32);
+        assert!(module.starts_with("advent_of_code::solution!(7, year = 2015);"));
+    }
+
+    #[test]
+    fn renders_custom_placeholders() {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let template = "//! Day DAY_NUMBER (YEAR_NUMBER): PUZZLE_TITLE\nfn f() -> ANSWER_TYPE {}";
+        assert_eq!(
+            render(template, puzzle, Some("Trebuchet?!"), AnswerType::String),
+            "//! Day 1 (2023): Trebuchet?!\nfn f() -> String {}"
+        );
+        assert_eq!(
+            render(template, puzzle, None, AnswerType::I64),
+            "//! Day 1 (2023): \nfn f() -> i64 {}"
+        );
+    }
+
+    #[test]
+    fn inserts_module_after_last_declaration() {
+        let lib = "mod day;\npub mod template;\npub use day::*;\npub mod day_1;\n\nmod tests {}\n";
+        assert_eq!(
+            insert_module(lib, "day_2").unwrap(),
+            "mod day;\npub mod template;\npub use day::*;\npub mod day_1;\npub mod day_2;\n\nmod tests {}\n"
+        );
+    }
+
+    #[test]
+    fn skips_declared_module() {
+        let lib = "pub mod day_1;\npub mod day_2;\n";
+        assert_eq!(insert_module(lib, "day_2"), None);
+        assert_eq!(insert_module("mod day_2;\n", "day_2"), None);
+    }
 }
@@ -0,0 +1,68 @@
+use std::process;
+
+use crate::template::{aoc_web, session};
+use crate::Year;
+
+pub enum Action {
+    Set { cookie: String, global: bool },
+    Show,
+    Check,
+}
+
+pub fn handle(action: Action, year: Year) {
+    match action {
+        Action::Set { cookie, global } => match session::store(&cookie, global) {
+            Ok(path) => println!("🎄 Stored session cookie in \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to store session cookie: {e}");
+                process::exit(1);
+            }
+        },
+        Action::Show => match session::find() {
+            Some(session) => {
+                println!("Session cookie: {}", session::mask(&session.cookie));
+                println!("Loaded from {}.", session.source);
+            }
+            None => {
+                eprintln!("{}", session::Error::NotFound);
+                process::exit(1);
+            }
+        },
+        Action::Check => check(year),
+    }
+}
+
+fn check(year: Year) {
+    if aoc_web::check().is_err() {
+        eprintln!("command \"curl\" not found or not callable. Install curl to validate the session cookie.");
+        process::exit(1);
+    }
+
+    match session::check(year) {
+        Ok(user) => {
+            let stars = user.stars.map(|s| format!(" ({s}⭐)")).unwrap_or_default();
+            println!(
+                "🎄 Session cookie is valid and belongs to {}{stars}.",
+                user.name
+            );
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Validates the session cookie before talking to the website, so an expired cookie
+/// is reported as such instead of surfacing as an opaque aoc-cli failure.
+/// Validation is skipped if curl is not available.
+pub fn require_valid(year: Year) {
+    if aoc_web::check().is_err() {
+        return;
+    }
+
+    if let Err(e) = session::check(year) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
@@ -1,9 +1,35 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::Day;
+use crate::template::commands::all::child_commands;
+use crate::template::{paths, readme_benchmarks};
+use crate::{Part, PuzzleId, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    time: bool,
+    submit_part: Option<Part>,
+    update_readme: bool,
+) {
+    if submit_part == Some(Part::Two) && !puzzle.day.has_part_two() {
+        eprintln!("Day {} only has a single part.", puzzle.day);
+        process::exit(1);
+    }
+
+    if update_readme {
+        if !release || !time || submit_part.is_some() {
+            eprintln!("`--update-readme` requires `--release --time` and can not be combined with `--submit`.");
+            process::exit(1);
+        }
+        bench_and_update_readme(puzzle);
+        return;
+    }
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        paths::get_bin_name(puzzle),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
@@ -29,3 +55,37 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Benchmarks the solution and replaces its row in the readme benchmark table.
+fn bench_and_update_readme(puzzle: PuzzleId) {
+    // the benchmark table in the readme tracks the configured year only.
+    if !puzzle.year.is_configured() {
+        eprintln!("The readme benchmarks only track {}.", Year::configured());
+        process::exit(1);
+    }
+
+    let output = match child_commands::run_solution(puzzle, true, true) {
+        Ok(output) if !output.is_empty() => output,
+        Ok(_) => {
+            eprintln!("Day {} is not solved yet.", puzzle.day);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let timings = child_commands::parse_exec_time(&output, puzzle.day);
+
+    match readme_benchmarks::update_day(timings) {
+        Ok(()) => println!(
+            "Successfully updated README with benchmarks of day {}.",
+            puzzle.day
+        ),
+        Err(_) => {
+            eprintln!("Failed to update readme with benchmarks.");
+            process::exit(1);
+        }
+    }
+}
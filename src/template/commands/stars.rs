@@ -0,0 +1,44 @@
+use std::process;
+
+use crate::template::commands::leaderboard;
+use crate::template::{readme_stars, submissions};
+use crate::Year;
+
+pub fn handle(year: Year, id: Option<u64>, file: Option<String>, user: Option<u64>) {
+    let history = match submissions::read() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = match leaderboard::load(year, id, file) {
+        Some(Ok(leaderboard)) => Some(leaderboard),
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        None => None,
+    };
+
+    // private leaderboards share the id of their owner, so default to the owner.
+    let member = leaderboard.as_ref().and_then(|leaderboard| {
+        let user = user.unwrap_or(leaderboard.owner_id);
+        let member = leaderboard.members.iter().find(|m| m.id == user);
+        if member.is_none() {
+            eprintln!("Warning: user #{user} is not a member of the leaderboard.");
+        }
+        member
+    });
+
+    let stars = readme_stars::collect(year, &history, member);
+
+    match readme_stars::update(year, stars) {
+        Ok(()) => println!("Successfully updated README with stars."),
+        Err(_) => {
+            eprintln!("Failed to update readme with stars.");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{fs, path::Path, process};
+
+use crate::template::commands::scaffold::{self, AnswerType};
+use crate::template::commands::download;
+use crate::template::{aoc_cli, paths, puzzle, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+/// Prepares everything to start solving `puzzle`. Steps that completed before are skipped,
+/// so the command can be run again if it fails partway.
+pub fn handle(puzzle: PuzzleId, answer_type: AnswerType, with_lib: bool) {
+    download_unless_present(puzzle);
+
+    // scaffold after downloading, so the template can include the puzzle title.
+    scaffold::create(puzzle, answer_type, with_lib, true);
+
+    fill_example(puzzle);
+
+    println!("---");
+    print_next_steps(puzzle);
+}
+
+fn download_unless_present(puzzle: PuzzleId) {
+    let input_path = paths::get_input_path(puzzle);
+    let puzzle_path = paths::get_puzzle_path(puzzle);
+
+    if !is_empty(&input_path) && Path::new(&puzzle_path).exists() {
+        println!("Input and puzzle description were downloaded already");
+        return;
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    download::download_at_unlock(puzzle);
+}
+
+/// Copies the example of part one into the example file, unless it has content already.
+fn fill_example(puzzle: PuzzleId) {
+    let example_path = paths::get_example_path(puzzle);
+
+    if !is_empty(&example_path) {
+        println!("Example file \"{example_path}\" has content already");
+        return;
+    }
+
+    let Some(example) = puzzle::read(puzzle).and_then(|p| p.example()) else {
+        println!("Could not find an example in the puzzle description, fill in \"{example_path}\" by hand");
+        return;
+    };
+
+    match fs::write(&example_path, example) {
+        Ok(()) => println!("Wrote example to \"{example_path}\""),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn is_empty(path: &str) -> bool {
+    fs::metadata(path).map_or(true, |m| m.len() == 0)
+}
+
+fn print_next_steps(puzzle: PuzzleId) {
+    let title = puzzle::read(puzzle).and_then(|p| p.title);
+    match title {
+        Some(title) => println!("🎄 {ANSI_BOLD}Day {}: {title}{ANSI_RESET}", puzzle.day),
+        None => println!("🎄 {ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day),
+    }
+
+    let year = if puzzle.year.is_configured() {
+        String::new()
+    } else {
+        format!(" --year {}", puzzle.year)
+    };
+
+    let day = puzzle.day;
+    println!();
+    println!("Next steps:");
+    println!(
+        "  1. `cargo test --bin {}` checks your solution against the example.",
+        paths::get_bin_name(puzzle)
+    );
+    println!("  2. `cargo solve {day}{year}` runs it against your input.");
+    println!("  3. `cargo solve {day}{year} --submit 1` submits part one.");
+}
@@ -0,0 +1,306 @@
+/// Module that checks the workspace against the conventions of the template.
+/// The checks work on file names and contents, reading the files is up to the caller.
+use std::fmt::Display;
+
+use crate::template::paths;
+use crate::{Day, Part, PuzzleId};
+
+/// A deviation from the conventions of the template, with a suggestion how to resolve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub subject: String,
+    pub problem: String,
+    pub fix: String,
+}
+
+impl Issue {
+    pub fn new(
+        subject: impl Into<String>,
+        problem: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            problem: problem.into(),
+            fix: fix.into(),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}\n  fix: {}", self.subject, self.problem, self.fix)
+    }
+}
+
+/// The invocation of a template command for `puzzle`, e.g. `cargo download 6`.
+fn command(name: &str, puzzle: PuzzleId) -> String {
+    if puzzle.year.is_configured() {
+        format!("cargo {name} {}", puzzle.day.into_inner())
+    } else {
+        format!(
+            "cargo {name} {} --year {}",
+            puzzle.day.into_inner(),
+            puzzle.year
+        )
+    }
+}
+
+/// Checks a solution binary along with its example and input, [`None`] meaning the file does not exist.
+#[must_use]
+pub fn check_solution(
+    puzzle: PuzzleId,
+    source: &str,
+    example: Option<&str>,
+    input: Option<&str>,
+) -> Vec<Issue> {
+    let bin = paths::get_path_for_bin(puzzle);
+    let example_path = paths::get_example_path(puzzle);
+    let input_path = paths::get_input_path(puzzle);
+    let mut issues = vec![];
+
+    match example {
+        None => issues.push(Issue::new(
+            &bin,
+            format!("example file \"{example_path}\" is missing"),
+            format!(
+                "run `{}` or create the file by hand",
+                command("start", puzzle)
+            ),
+        )),
+        Some(example) if example.trim().is_empty() => issues.push(Issue::new(
+            &example_path,
+            "example file is empty",
+            "paste the example from the puzzle description",
+        )),
+        Some(_) => {}
+    }
+
+    match input {
+        None => issues.push(Issue::new(
+            &bin,
+            format!("input file \"{input_path}\" is missing"),
+            format!("run `{}`", command("download", puzzle)),
+        )),
+        Some(input) if input.trim().is_empty() => issues.push(Issue::new(
+            &input_path,
+            "input file is empty",
+            format!("run `{}`", command("download", puzzle)),
+        )),
+        Some(_) => {}
+    }
+
+    let code: Vec<&str> = source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect();
+
+    let reads_example = code.iter().any(|line| {
+        line.contains("read_file(\"examples\"") || line.contains("read_file_part(\"examples\"")
+    });
+    if source.contains("#[cfg(test)]") && !reads_example {
+        issues.push(Issue::new(
+            &bin,
+            "tests do not read the example file",
+            "use `advent_of_code::template::read_file(\"examples\", DAY)` in the tests",
+        ));
+    }
+
+    for test in tests_asserting_none(&code) {
+        issues.push(Issue::new(
+            &bin,
+            format!("`{test}` still asserts `None`"),
+            "replace `None` with the expected answer of the example",
+        ));
+    }
+
+    issues
+}
+
+/// Returns the names of the test functions that still contain the assertion of the scaffold.
+fn tests_asserting_none<'a>(code: &[&'a str]) -> Vec<&'a str> {
+    let mut current_fn = None;
+    let mut tests = vec![];
+
+    for line in code {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("fn ") {
+            current_fn = rest.split('(').next();
+        }
+        if line == "assert_eq!(result, None);" {
+            if let Some(name) = current_fn.take() {
+                tests.push(name);
+            }
+        }
+    }
+
+    tests
+}
+
+/// The day a data file belongs to, if its name follows the conventions of `folder`:
+/// `01.txt` for inputs, `01.txt` or `01-2.txt` for examples and `01.md` for puzzles.
+#[must_use]
+pub fn parse_data_file_name(folder: &str, file_name: &str) -> Option<Day> {
+    let (stem, ext) = file_name.rsplit_once('.')?;
+
+    let stem = match (folder, ext) {
+        ("inputs", "txt") => stem,
+        ("examples", "txt") => match stem.split_once('-') {
+            Some((day, part)) => part.parse::<Part>().ok().map(|_| day)?,
+            None => stem,
+        },
+        ("puzzles", "md") => stem,
+        _ => return None,
+    };
+
+    let day: Day = stem.parse().ok()?;
+    (day.to_string() == stem).then_some(day)
+}
+
+/// Checks a file in a data folder of the year of `solutions`.
+/// Files that do not follow the naming conventions or belong to no solution are reported.
+#[must_use]
+pub fn check_data_file(
+    path: &str,
+    folder: &str,
+    file_name: &str,
+    solutions: &[PuzzleId],
+) -> Option<Issue> {
+    if file_name.starts_with('.') {
+        return None;
+    }
+
+    let Some(day) = parse_data_file_name(folder, file_name) else {
+        return Some(Issue::new(
+            path,
+            "file name does not follow the conventions of the template",
+            "rename or remove the file",
+        ));
+    };
+
+    if solutions.iter().any(|puzzle| puzzle.day == day) {
+        return None;
+    }
+
+    Some(Issue::new(
+        path,
+        format!("there is no solution for day {day}"),
+        "scaffold the day or remove the file",
+    ))
+}
+
+/// Checks that each of `modules` (e.g. `day_3`) is declared in the contents of `lib.rs`.
+#[must_use]
+pub fn check_lib_modules(lib: &str, modules: &[String]) -> Vec<Issue> {
+    modules
+        .iter()
+        .filter(|module| {
+            !lib.lines()
+                .any(|line| line.trim() == format!("pub mod {module};"))
+        })
+        .map(|module| {
+            Issue::new(
+                format!("./src/{module}.rs"),
+                "module is not exported from \"src/lib.rs\"",
+                format!("add `pub mod {module};` to \"src/lib.rs\""),
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_data_file, check_lib_modules, check_solution, parse_data_file_name};
+    use crate::{day, PuzzleId, Year};
+
+    const SCAFFOLD: &str = r#"advent_of_code::solution!(1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(142));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(Year::configured(), day!(1))
+    }
+
+    #[test]
+    fn reports_tests_asserting_none() {
+        let issues = check_solution(puzzle(), SCAFFOLD, Some("1abc2"), Some("input"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].problem, "`test_part_two` still asserts `None`");
+    }
+
+    #[test]
+    fn reports_missing_and_empty_files() {
+        let issues = check_solution(puzzle(), SCAFFOLD, None, Some(""));
+        assert_eq!(issues[0].subject, "./src/bin/01.rs");
+        assert_eq!(
+            issues[0].fix,
+            "run `cargo start 1` or create the file by hand"
+        );
+        assert_eq!(issues[1].subject, "data/inputs/01.txt");
+        assert_eq!(issues[1].problem, "input file is empty");
+    }
+
+    #[test]
+    fn reports_tests_ignoring_the_example() {
+        let source = SCAFFOLD.replace(
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "// let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+        );
+        let source = source.replace(
+            "let result = part_two(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "let result = part_two(\"not_needed\");",
+        );
+        let issues = check_solution(puzzle(), &source, Some("1abc2"), Some("input"));
+        assert!(issues
+            .iter()
+            .any(|i| i.problem == "tests do not read the example file"));
+    }
+
+    #[test]
+    fn parses_data_file_names() {
+        assert_eq!(parse_data_file_name("inputs", "03.txt"), Some(day!(3)));
+        assert_eq!(parse_data_file_name("examples", "03-2.txt"), Some(day!(3)));
+        assert_eq!(parse_data_file_name("puzzles", "03.md"), Some(day!(3)));
+        assert_eq!(parse_data_file_name("examples", "03 copy.txt"), None);
+        assert_eq!(parse_data_file_name("examples", "3.txt"), None);
+        assert_eq!(parse_data_file_name("inputs", "03-2.txt"), None);
+        assert_eq!(parse_data_file_name("puzzles", "03.txt"), None);
+    }
+
+    #[test]
+    fn reports_orphan_data_files() {
+        let solutions = [puzzle()];
+        let check = |name: &str| check_data_file(name, "examples", name, &solutions);
+        assert_eq!(check("01.txt"), None);
+        assert_eq!(check(".keep"), None);
+        assert!(check("03 copy.txt").is_some());
+        assert_eq!(
+            check("02.txt").unwrap().problem,
+            "there is no solution for day 02"
+        );
+    }
+
+    #[test]
+    fn reports_unexported_modules() {
+        let lib = "pub mod template;\npub mod day_1;\n";
+        let issues = check_lib_modules(lib, &["day_1".into(), "day_2".into()]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].fix, "add `pub mod day_2;` to \"src/lib.rs\"");
+    }
+}
@@ -0,0 +1,343 @@
+/// Module that parses and renders private leaderboards.
+/// The JSON format is documented on the leaderboard page (`[API]` link) of the Advent of Code website.
+use std::{fmt::Write, fs, io};
+
+use serde_json::Value;
+
+use crate::template::{aoc_web, datetime, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{all_days, Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Parser(String),
+    Web(aoc_web::AocWebError),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid leaderboard JSON: {e}"),
+            Error::Parser(reason) => write!(f, "unexpected leaderboard format: {reason}"),
+            Error::Web(e) => write!(f, "could not fetch leaderboard: {e}"),
+            Error::IO(e) => write!(f, "could not read leaderboard: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Members sorted by local score, best first.
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u64,
+    pub local_score: u64,
+    pub last_star_ts: Option<i64>,
+    /// Completed days sorted by day.
+    pub completion: Vec<Completion>,
+}
+
+/// Unix timestamps at which the stars of a day were collected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    pub day: Day,
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl Member {
+    /// The display name, falling back to the anonymous name the website uses.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    #[must_use]
+    pub fn completion_for(&self, day: Day) -> Option<&Completion> {
+        self.completion.iter().find(|c| c.day == day)
+    }
+}
+
+#[must_use]
+pub fn get_path_for_leaderboard(year: Year, id: u64) -> String {
+    format!("{year}/leaderboard/private/view/{id}.json")
+}
+
+pub fn read(path: &str) -> Result<Leaderboard, Error> {
+    parse(&fs::read_to_string(path)?)
+}
+
+pub fn fetch(year: Year, id: u64) -> Result<Leaderboard, Error> {
+    let json = aoc_web::get(&get_path_for_leaderboard(year, id)).map_err(Error::Web)?;
+    parse(&json)
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, Error> {
+    let value: Value = serde_json::from_str(json)?;
+
+    let event = match &value["event"] {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        _ => return Err(Error::Parser("missing \"event\".".into())),
+    };
+
+    let owner_id =
+        as_u64(&value["owner_id"]).ok_or_else(|| Error::Parser("missing \"owner_id\".".into()))?;
+
+    let mut members = value["members"]
+        .as_object()
+        .ok_or_else(|| Error::Parser("missing \"members\".".into()))?
+        .values()
+        .map(parse_member)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.id.cmp(&b.id))
+    });
+
+    Ok(Leaderboard {
+        event,
+        owner_id,
+        members,
+    })
+}
+
+fn parse_member(value: &Value) -> Result<Member, Error> {
+    let id = as_u64(&value["id"]).ok_or_else(|| Error::Parser("member without \"id\".".into()))?;
+
+    let mut completion = match value["completion_day_level"].as_object() {
+        Some(days) => days
+            .iter()
+            .map(|(day, parts)| {
+                let day: Day = day
+                    .parse()
+                    .map_err(|_| Error::Parser(format!("invalid day \"{day}\".")))?;
+                Ok(Completion {
+                    day,
+                    part_1: parts["1"]["get_star_ts"].as_i64(),
+                    part_2: parts["2"]["get_star_ts"].as_i64(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?,
+        None => vec![],
+    };
+
+    completion.sort_by_key(|c| c.day);
+
+    Ok(Member {
+        id,
+        name: value["name"].as_str().map(str::to_string),
+        stars: value["stars"].as_u64().unwrap_or(0),
+        local_score: value["local_score"].as_u64().unwrap_or(0),
+        last_star_ts: value["last_star_ts"].as_i64().filter(|ts| *ts > 0),
+        completion,
+    })
+}
+
+/// Ids are numbers in the JSON API, but the member map is keyed by strings, so accept both.
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Renders one character per day: `*` for both stars, `+` for only the first star, `.` for none.
+fn star_row(member: &Member) -> String {
+    all_days()
+        .map(|day| match member.completion_for(day) {
+            Some(Completion {
+                part_2: Some(_), ..
+            }) => '*',
+            Some(Completion {
+                part_1: Some(_), ..
+            }) => '+',
+            _ => '.',
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn render(leaderboard: &Leaderboard) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{ANSI_BOLD}Private leaderboard {} (#{}){ANSI_RESET}",
+        leaderboard.event, leaderboard.owner_id
+    );
+    let _ = writeln!(
+        out,
+        "{ANSI_ITALIC}* both stars, + first star only, . not solved{ANSI_RESET}"
+    );
+    out.push('\n');
+
+    let _ = writeln!(
+        out,
+        "     {:<25}  Stars  Score",
+        "         1111111111222222"
+    );
+    let _ = writeln!(out, "     {:<25}", "1234567890123456789012345");
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{:>3}) {}  {:>5}  {:>5}  {}",
+            rank + 1,
+            star_row(member),
+            member.stars,
+            member.local_score,
+            member.display_name()
+        );
+    }
+
+    for member in &leaderboard.members {
+        if member.completion.is_empty() {
+            continue;
+        }
+
+        out.push('\n');
+        let _ = writeln!(out, "{ANSI_BOLD}{}{ANSI_RESET}", member.display_name());
+
+        for completion in &member.completion {
+            let format_part =
+                |ts: Option<i64>| ts.map_or_else(|| "-".to_string(), datetime::format_timestamp);
+            let _ = writeln!(
+                out,
+                "  Day {}: {} | {}",
+                completion.day,
+                format_part(completion.part_1),
+                format_part(completion.part_2)
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, render, star_row, Completion};
+    use crate::day;
+
+    fn get_mock_json() -> &'static str {
+        r#"{
+            "event": "2023",
+            "owner_id": 1000,
+            "members": {
+                "1000": {
+                    "id": 1000,
+                    "name": "Ed",
+                    "stars": 3,
+                    "local_score": 10,
+                    "global_score": 0,
+                    "last_star_ts": 1701494000,
+                    "completion_day_level": {
+                        "2": { "1": { "get_star_ts": 1701494000, "star_index": 2 } },
+                        "1": {
+                            "1": { "get_star_ts": 1701407112, "star_index": 0 },
+                            "2": { "get_star_ts": 1701407500, "star_index": 1 }
+                        }
+                    }
+                },
+                "2000": {
+                    "id": 2000,
+                    "name": null,
+                    "stars": 4,
+                    "local_score": 12,
+                    "global_score": 0,
+                    "last_star_ts": 0,
+                    "completion_day_level": {}
+                }
+            }
+        }"#
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = parse(get_mock_json()).unwrap();
+        assert_eq!(leaderboard.event, "2023");
+        assert_eq!(leaderboard.owner_id, 1000);
+        assert_eq!(leaderboard.members.len(), 2);
+
+        let ed = &leaderboard.members[1];
+        assert_eq!(ed.display_name(), "Ed");
+        assert_eq!(ed.local_score, 10);
+        assert_eq!(
+            ed.completion,
+            vec![
+                Completion {
+                    day: day!(1),
+                    part_1: Some(1_701_407_112),
+                    part_2: Some(1_701_407_500),
+                },
+                Completion {
+                    day: day!(2),
+                    part_1: Some(1_701_494_000),
+                    part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn sorts_members_by_local_score() {
+        let leaderboard = parse(get_mock_json()).unwrap();
+        let ids: Vec<u64> = leaderboard.members.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![2000, 1000]);
+    }
+
+    #[test]
+    fn names_anonymous_members() {
+        let leaderboard = parse(get_mock_json()).unwrap();
+        assert_eq!(
+            leaderboard.members[0].display_name(),
+            "(anonymous user #2000)"
+        );
+        assert_eq!(leaderboard.members[0].last_star_ts, None);
+    }
+
+    #[test]
+    fn renders_star_rows() {
+        let leaderboard = parse(get_mock_json()).unwrap();
+        assert_eq!(
+            star_row(&leaderboard.members[1]),
+            format!("*+{}", ".".repeat(23))
+        );
+
+        let rendered = render(&leaderboard);
+        assert!(rendered.contains("Day 01: 2023-12-01 05:05:12 UTC | 2023-12-01 05:11:40 UTC"));
+        assert!(rendered.contains("Day 02: 2023-12-02 05:13:20 UTC | -"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_missing_members() {
+        parse(r#"{ "event": "2023", "owner_id": 1 }"#).unwrap();
+    }
+}
@@ -1,10 +1,21 @@
-use crate::Day;
+use crate::{Part, PuzzleId};
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_web;
+pub mod benchmark_chart;
 pub mod commands;
+pub mod datetime;
+pub mod doctor;
+pub mod leaderboard;
+pub mod paths;
+pub mod puzzle;
+pub mod readme;
 pub mod readme_benchmarks;
+pub mod readme_stars;
 pub mod runner;
+pub mod session;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -12,37 +23,74 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(paths::get_data_path(folder, puzzle.into(), "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: impl Into<PuzzleId>, part: Part) -> String {
+    let puzzle = puzzle.into();
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(paths::get_data_dir(puzzle.year))
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// Solutions for a year other than `AOC_YEAR` pass the year as well, e.g. `solution!(1, year = 2022)`.
+/// In that case, `DAY` is a [`PuzzleId`](crate::PuzzleId) instead of a [`Day`](crate::Day).
+///
+/// Day 25 only has a single part, so its solution does not need to define `part_two`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
-
+    (@main) => {
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = advent_of_code::template::read_file("inputs", DAY);
+            run_part(part_one, &input, DAY, advent_of_code::Part::One);
+            run_part(part_two, &input, DAY, advent_of_code::Part::Two);
+        }
+    };
+    (@main single_part) => {
         fn main() {
             use advent_of_code::template::runner::*;
             let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            run_part(part_one, &input, DAY, advent_of_code::Part::One);
         }
     };
+    (@day $day:expr) => {
+        /// The current day.
+        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    };
+    (@day $day:expr, $year:expr) => {
+        /// The current puzzle.
+        const DAY: advent_of_code::PuzzleId = advent_of_code::PuzzleId {
+            year: advent_of_code::year!($year),
+            day: advent_of_code::day!($day),
+        };
+    };
+    (25) => {
+        $crate::solution!(@day 25);
+        $crate::solution!(@main single_part);
+    };
+    (25, year = $year:expr) => {
+        $crate::solution!(@day 25, $year);
+        $crate::solution!(@main single_part);
+    };
+    ($day:expr) => {
+        $crate::solution!(@day $day);
+        $crate::solution!(@main);
+    };
+    ($day:expr, year = $year:expr) => {
+        $crate::solution!(@day $day, $year);
+        $crate::solution!(@main);
+    };
 }
@@ -0,0 +1,124 @@
+/// Module that maps puzzles to the files that belong to them.
+///
+/// Puzzles of the [configured](crate::Year::configured) year use the flat layout of the template,
+/// e.g. `data/inputs/01.txt` and `src/bin/01.rs`. Puzzles of other years are namespaced by year,
+/// e.g. `data/2022/inputs/01.txt` and `src/bin/2022-01.rs`.
+use crate::{PuzzleId, Year};
+
+/// The directory that holds the data folders (`inputs`, `examples`, `puzzles`) of `year`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> String {
+    if year.is_configured() {
+        "data".into()
+    } else {
+        format!("data/{year}")
+    }
+}
+
+/// The path of a data file, e.g. `data/inputs/01.txt` for folder `inputs` and extension `txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, ext: &str) -> String {
+    format!(
+        "{}/{folder}/{}.{ext}",
+        get_data_dir(puzzle.year),
+        puzzle.day
+    )
+}
+
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    get_data_path("inputs", puzzle, "txt")
+}
+
+#[must_use]
+pub fn get_example_path(puzzle: PuzzleId) -> String {
+    get_data_path("examples", puzzle, "txt")
+}
+
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    get_data_path("puzzles", puzzle, "md")
+}
+
+/// The name of the solution binary, as passed to `cargo run --bin`.
+#[must_use]
+pub fn get_bin_name(puzzle: PuzzleId) -> String {
+    if puzzle.year.is_configured() {
+        puzzle.day.to_string()
+    } else {
+        format!("{}-{}", puzzle.year, puzzle.day)
+    }
+}
+
+/// The puzzle a solution binary belongs to, the inverse of [`get_bin_name`].
+#[must_use]
+pub fn parse_bin_name(name: &str) -> Option<PuzzleId> {
+    match name.split_once('-') {
+        Some((year, day)) => Some(PuzzleId::new(year.parse().ok()?, day.parse().ok()?)),
+        None => Some(PuzzleId::new(Year::configured(), name.parse().ok()?)),
+    }
+}
+
+#[must_use]
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(puzzle))
+}
+
+/// The name of the library module with reusable code for a puzzle, as declared in `lib.rs`.
+#[must_use]
+pub fn get_lib_module_name(puzzle: PuzzleId) -> String {
+    let day = puzzle.day.into_inner();
+    if puzzle.year.is_configured() {
+        format!("day_{day}")
+    } else {
+        format!("y{}_day_{day}", puzzle.year)
+    }
+}
+
+#[must_use]
+pub fn get_path_for_lib_module(puzzle: PuzzleId) -> String {
+    format!("./src/{}.rs", get_lib_module_name(puzzle))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        get_bin_name, get_input_path, get_lib_module_name, get_path_for_bin,
+        get_path_for_lib_module, get_puzzle_path, parse_bin_name,
+    };
+    use crate::{day, PuzzleId, Year};
+
+    #[test]
+    fn uses_flat_layout_for_configured_year() {
+        let puzzle = PuzzleId::new(Year::configured(), day!(1));
+        assert_eq!(get_input_path(puzzle), "data/inputs/01.txt");
+        assert_eq!(get_puzzle_path(puzzle), "data/puzzles/01.md");
+        assert_eq!(get_bin_name(puzzle), "01");
+        assert_eq!(get_path_for_bin(puzzle), "./src/bin/01.rs");
+        assert_eq!(get_lib_module_name(puzzle), "day_1");
+        assert_eq!(get_path_for_lib_module(puzzle), "./src/day_1.rs");
+    }
+
+    #[test]
+    fn namespaces_other_years() {
+        let year = Year::new(2015).unwrap();
+        let puzzle = PuzzleId::new(year, day!(7));
+        assert_eq!(get_input_path(puzzle), "data/2015/inputs/07.txt");
+        assert_eq!(get_puzzle_path(puzzle), "data/2015/puzzles/07.md");
+        assert_eq!(get_bin_name(puzzle), "2015-07");
+        assert_eq!(get_path_for_bin(puzzle), "./src/bin/2015-07.rs");
+        assert_eq!(get_lib_module_name(puzzle), "y2015_day_7");
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        let configured = PuzzleId::new(Year::configured(), day!(1));
+        assert_eq!(parse_bin_name("01"), Some(configured));
+        assert_eq!(parse_bin_name(&get_bin_name(configured)), Some(configured));
+
+        let other = PuzzleId::new(Year::new(2015).unwrap(), day!(7));
+        assert_eq!(parse_bin_name("2015-07"), Some(other));
+        assert_eq!(parse_bin_name("main"), None);
+        assert_eq!(parse_bin_name("2015-26"), None);
+    }
+}
@@ -0,0 +1,182 @@
+/// Module that inspects puzzle descriptions saved by aoc-cli to the `puzzles` data folder.
+use std::fs;
+
+use regex::Regex;
+
+use crate::template::paths;
+use crate::{Part, PuzzleId};
+
+/// The description of a puzzle, split into its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: Option<String>,
+    pub part_one: String,
+    /// Only visible after part one was solved.
+    pub part_two: Option<String>,
+}
+
+impl Puzzle {
+    /// The part the user is currently working on, based on which parts are visible.
+    #[must_use]
+    pub fn current_part(&self) -> Part {
+        if self.part_two.is_some() {
+            Part::Two
+        } else {
+            Part::One
+        }
+    }
+
+    /// The description of the part the user is currently working on.
+    #[must_use]
+    pub fn current_description(&self) -> &str {
+        self.part_two.as_deref().unwrap_or(&self.part_one)
+    }
+
+    /// The example input of part one. This is the first code block right after a paragraph that
+    /// mentions an example, or the first code block of part one if no such paragraph exists.
+    #[must_use]
+    pub fn example(&self) -> Option<String> {
+        let blocks = code_blocks(&self.part_one);
+        blocks
+            .iter()
+            .find(|(preceding, _)| preceding.to_lowercase().contains("example"))
+            .or_else(|| blocks.first())
+            .map(|(_, block)| block.clone())
+    }
+}
+
+/// Returns the fenced code blocks of a description, each with the paragraph preceding it.
+fn code_blocks(md: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut paragraph_ended = false;
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in md.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+        match (&mut block, is_fence) {
+            (None, true) => block = Some(vec![]),
+            (None, false) if line.trim().is_empty() => paragraph_ended = true,
+            (None, false) => {
+                // only the paragraph right before the block is relevant.
+                if paragraph_ended {
+                    paragraph.clear();
+                    paragraph_ended = false;
+                }
+                paragraph.push(line.trim());
+            }
+            (Some(lines), true) => {
+                let mut content = lines.join("\n");
+                content.push('\n');
+                blocks.push((paragraph.join(" "), content));
+                paragraph.clear();
+                paragraph_ended = false;
+                block = None;
+            }
+            (Some(lines), false) => lines.push(line),
+        }
+    }
+
+    blocks
+}
+
+/// Reads the saved description of `puzzle`, if it was downloaded already.
+#[must_use]
+pub fn read(puzzle: PuzzleId) -> Option<Puzzle> {
+    fs::read_to_string(paths::get_puzzle_path(puzzle))
+        .ok()
+        .map(|md| parse(&md))
+}
+
+/// Parses a puzzle description. aoc-cli escapes the leading dashes of headings, so both
+/// `--- Part Two ---` and `\--- Part Two ---` are recognized.
+#[must_use]
+pub fn parse(md: &str) -> Puzzle {
+    let title = Regex::new(r"\\?--- Day \d+: (?<title>.+?) ---")
+        .unwrap()
+        .captures(md)
+        .map(|c| c["title"].trim().to_string());
+
+    let part_two_re = Regex::new(r"(?m)^\\?--- Part Two ---").unwrap();
+
+    let (part_one, part_two) = match part_two_re.find(md) {
+        Some(m) => (&md[..m.start()], Some(md[m.start()..].trim().to_string())),
+        None => (md, None),
+    };
+
+    Puzzle {
+        title,
+        part_one: part_one.trim().to_string(),
+        part_two,
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse;
+    use crate::Part;
+
+    #[test]
+    fn parses_part_one() {
+        let puzzle = parse("\\--- Day 1: Trebuchet?! ---\n\nSomething is wrong.\n");
+        assert_eq!(puzzle.title.as_deref(), Some("Trebuchet?!"));
+        assert_eq!(
+            puzzle.part_one,
+            "\\--- Day 1: Trebuchet?! ---\n\nSomething is wrong."
+        );
+        assert_eq!(puzzle.part_two, None);
+        assert_eq!(puzzle.current_part(), Part::One);
+    }
+
+    #[test]
+    fn splits_parts() {
+        let puzzle =
+            parse("--- Day 3: Gear Ratios ---\n\nPart one.\n\n--- Part Two ---\n\nPart two.\n");
+        assert_eq!(puzzle.title.as_deref(), Some("Gear Ratios"));
+        assert_eq!(puzzle.part_one, "--- Day 3: Gear Ratios ---\n\nPart one.");
+        assert_eq!(
+            puzzle.part_two.as_deref(),
+            Some("--- Part Two ---\n\nPart two.")
+        );
+        assert_eq!(puzzle.current_part(), Part::Two);
+        assert_eq!(
+            puzzle.current_description(),
+            "--- Part Two ---\n\nPart two."
+        );
+    }
+
+    #[test]
+    fn handles_missing_title() {
+        let puzzle = parse("no heading");
+        assert_eq!(puzzle.title, None);
+        assert_eq!(puzzle.current_description(), "no heading");
+    }
+
+    #[test]
+    fn extracts_example() {
+        let puzzle = parse(
+            "\\--- Day 1: Trebuchet?! ---\n\nSome `code` first:\n\n```\nnot this\n```\n\nFor example:\n\n```\n1abc2\npqr3stu8vwx\n```\n\nIn this example...\n",
+        );
+        assert_eq!(puzzle.example().as_deref(), Some("1abc2\npqr3stu8vwx\n"));
+    }
+
+    #[test]
+    fn reads_whole_paragraphs() {
+        let puzzle = parse(
+            "Intro:\n\n```\nnot this\n```\n\nFor example, consider\nthe following list:\n\n```\n3   4\n```\n\nAn example.\nNot a list:\n```\nno\n```\n",
+        );
+        assert_eq!(puzzle.example().as_deref(), Some("3   4\n"));
+
+        let puzzle = parse(
+            "An example follows.\n\nSomething else:\n\n```\nno\n```\n\nFor\nexample:\n\n```\nyes\n```\n",
+        );
+        assert_eq!(puzzle.example().as_deref(), Some("yes\n"));
+    }
+
+    #[test]
+    fn falls_back_to_first_code_block() {
+        let puzzle = parse("--- Day 2: Cube Conundrum ---\n\nGiven:\n\n```\nGame 1\n```\n");
+        assert_eq!(puzzle.example().as_deref(), Some("Game 1\n"));
+        assert_eq!(parse("no code").example(), None);
+    }
+}
@@ -0,0 +1,97 @@
+/// Module that locates and replaces marker-delimited tables in the readme.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+pub struct TablePosition {
+    pos_start: usize,
+    pos_end: usize,
+}
+
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
+    }
+
+    let pos_start = matches
+        .first()
+        .map(|m| m.0)
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Returns everything between (and including) the two occurences of `marker`.
+pub fn read_table<'a>(s: &'a str, marker: &str) -> Result<&'a str, Error> {
+    let positions = locate_table(s, marker)?;
+    Ok(&s[positions.pos_start..positions.pos_end])
+}
+
+/// Replaces everything between (and including) the two occurences of `marker` with `table`.
+/// `table` is expected to start and end with `marker` itself.
+pub fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{locate_table, read_table, replace_table};
+
+    const MARKER: &str = "<!--- some table --->";
+
+    #[test]
+    fn locates_table_between_markers() {
+        let s = format!("foo\n{MARKER}\nbar\n{MARKER}\nbaz");
+        let position = locate_table(&s, MARKER).unwrap();
+        assert_eq!(position.pos_start, 4);
+        assert_eq!(&s[position.pos_end..], "\nbaz");
+    }
+
+    #[test]
+    fn reads_table_between_markers() {
+        let s = format!("foo\n{MARKER}\nbar\n{MARKER}\nbaz");
+        assert_eq!(
+            read_table(&s, MARKER).unwrap(),
+            format!("{MARKER}\nbar\n{MARKER}")
+        );
+    }
+
+    #[test]
+    fn ignores_other_markers() {
+        let mut s = format!("{MARKER}{MARKER}\n<!--- other --->\n<!--- other --->");
+        replace_table(&mut s, MARKER, &format!("{MARKER}\ntable\n{MARKER}")).unwrap();
+        assert_eq!(
+            s,
+            format!("{MARKER}\ntable\n{MARKER}\n<!--- other --->\n<!--- other --->")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        replace_table(&mut s, MARKER, "").unwrap();
+    }
+}
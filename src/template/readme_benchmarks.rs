@@ -1,23 +1,147 @@
 /// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::path::{Component, Path};
+use std::process::Command;
+use std::{env, fs};
 
+use regex::Regex;
+
+use crate::template::{benchmark_chart, datetime, paths, readme};
 use crate::Day;
 
+pub use crate::template::readme::Error;
+
 static MARKER: &str = "<!--- benchmarking table --->";
+static CHART_PATH: &str = ".assets/benchmarks.svg";
+
+/// Where the benchmark table is written and how it looks.
+/// Configured via environment variables, usually set in `.cargo/config.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableConfig {
+    /// The file that contains the table, `AOC_BENCHMARK_FILE`.
+    pub path: String,
+    /// The comment that delimits the table, built from `AOC_BENCHMARK_MARKER`.
+    pub marker: String,
+    /// The level of the table heading, `AOC_BENCHMARK_HEADING_LEVEL`.
+    pub heading_level: usize,
+    /// Whether to record where the timings were measured, `AOC_BENCHMARK_METADATA`.
+    pub metadata: bool,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            path: "README.md".into(),
+            marker: MARKER.into(),
+            heading_level: 2,
+            metadata: false,
+        }
+    }
+}
+
+impl TableConfig {
+    /// Reads the configuration from the environment. Unset or invalid values fall back to the defaults.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let var = |name: &str| env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+        Self {
+            path: var("AOC_BENCHMARK_FILE").unwrap_or(default.path),
+            marker: var("AOC_BENCHMARK_MARKER")
+                .map_or(default.marker, |name| format!("<!--- {} --->", name.trim())),
+            heading_level: var("AOC_BENCHMARK_HEADING_LEVEL")
+                .and_then(|level| level.trim().parse().ok())
+                .filter(|level| (1..=6).contains(level))
+                .unwrap_or(default.heading_level),
+            metadata: var("AOC_BENCHMARK_METADATA")
+                .map_or(default.metadata, |value| value.trim() == "true"),
+        }
+    }
+
+    /// Makes `path`, relative to the root of the workspace, relative to the file that contains the table.
+    fn link(&self, path: &str) -> String {
+        let path = path.trim_start_matches("./");
+        let depth = Path::new(&self.path).parent().map_or(0, |dir| {
+            dir.components()
+                .filter(|c| matches!(c, Component::Normal(_)))
+                .count()
+        });
+
+        if depth == 0 {
+            format!("./{path}")
+        } else {
+            format!("{}{path}", "../".repeat(depth))
+        }
+    }
+}
 
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
+/// Describes the environment the timings were measured in.
+/// Each field is [`None`] if it could not be determined.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    pub rustc: Option<String>,
+    pub cpu: Option<String>,
+    pub profile: Option<String>,
+    pub date: Option<String>,
+    pub commit: Option<String>,
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+impl Metadata {
+    /// Collects the metadata of the current machine and workspace.
+    /// Benchmarks are only recorded for release builds, so that is the profile.
+    #[must_use]
+    pub fn collect() -> Self {
+        let stdout = |program: &str, args: &[&str]| {
+            let output = Command::new(program).args(args).output().ok()?;
+            let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (output.status.success() && !stdout.is_empty()).then_some(stdout)
+        };
+
+        let date = datetime::format_timestamp(datetime::now());
+
+        Self {
+            // e.g. `rustc 1.75.0 (82e1608df 2023-12-21)`
+            rustc: stdout("rustc", &["--version"])
+                .map(|version| version.split(" (").next().unwrap_or_default().to_string()),
+            cpu: fs::read_to_string("/proc/cpuinfo")
+                .ok()
+                .and_then(|cpuinfo| parse_cpu_model(&cpuinfo)),
+            profile: Some("release".into()),
+            date: date.split(' ').next().map(String::from),
+            commit: stdout("git", &["rev-parse", "--short", "HEAD"]),
+        }
+    }
+
+    /// Formats the metadata as a single line, or [`None`] if nothing is known.
+    fn format(&self) -> Option<String> {
+        let fields: Vec<String> = [
+            self.rustc.clone(),
+            self.cpu.clone(),
+            self.profile
+                .as_ref()
+                .map(|profile| format!("{profile} profile")),
+            self.date.clone(),
+            self.commit
+                .as_ref()
+                .map(|commit| format!("commit {commit}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        (!fields.is_empty()).then(|| format!("<sub>{}</sub>", fields.join(" · ")))
     }
 }
 
+/// Returns the CPU model of the first processor listed in the contents of `/proc/cpuinfo`.
+fn parse_cpu_model(cpuinfo: &str) -> Option<String> {
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        let value = value.trim();
+        (key.trim() == "model name" && !value.is_empty()).then(|| value.to_string())
+    })
+}
+
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
@@ -26,85 +150,178 @@ pub struct Timings {
     pub total_nanos: f64,
 }
 
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
-}
-
+/// Parses a duration as formatted by [`std::time::Duration`]'s `Debug` implementation into nanoseconds.
+/// For possible formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn parse_nanos(s: &str) -> Option<f64> {
+    let parse = |postfix: &str| s.split(postfix).next()?.parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse("ns"),
+        s if s.contains("µs") => parse("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse("ms").map(|x| x * 1_000_000_f64),
+        _ => parse("s").map(|x| x * 1_000_000_000_f64),
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Reads the timings back from a benchmark table.
+/// The total of each day is recomputed from the timings of its parts.
+fn parse_table(table: &str) -> Vec<Timings> {
+    let row_re =
+        Regex::new(r"^\| \[Day (?<day>\d+)\]\([^)]*\) \| (?<part_1>.*?) \| (?<part_2>.*?) \|$")
+            .unwrap();
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
-    }
+    let cell = |s: &str| {
+        let timing = s.trim().trim_matches('`');
+        (!timing.is_empty() && timing != "-").then(|| timing.to_string())
+    };
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    table
+        .lines()
+        .filter_map(|line| {
+            let caps = row_re.captures(line.trim())?;
+            let day = caps["day"].parse::<u8>().ok().and_then(Day::new)?;
+            let part_1 = cell(&caps["part_1"]);
+            let part_2 = cell(&caps["part_2"]);
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+            let total_nanos = [&part_1, &part_2]
+                .iter()
+                .filter_map(|timing| timing.as_deref().and_then(parse_nanos))
+                .sum();
 
-    Ok(TablePosition { pos_start, pos_end })
+            Some(Timings {
+                day,
+                part_1,
+                part_2,
+                total_nanos,
+            })
+        })
+        .collect()
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    config: &TableConfig,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    metadata: Option<&Metadata>,
+) -> String {
+    let header = format!("{} Benchmarks", "#".repeat(config.heading_level));
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        config.marker.clone(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
         "| :---: | :---: | :---:  |".into(),
     ];
 
+    let cell = |timing: Option<String>| format!("`{}`", timing.unwrap_or_else(|| "-".into()));
+    // the chart is only written if there is something to draw, see `benchmark_chart::render`.
+    let has_chart = !timings.is_empty();
+
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = config.link(&paths::get_path_for_bin(timing.day.into()));
+        // leave the cell empty for days without a second part instead of marking it as unsolved.
+        let part_2 = if timing.day.has_part_two() {
+            cell(timing.part_2)
+        } else {
+            String::new()
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cell(timing.part_1),
+            part_2
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    if let Some(line) = metadata.and_then(Metadata::format) {
+        lines.push(String::new());
+        lines.push(line);
+    }
+    if has_chart {
+        lines.push(String::new());
+        lines.push(format!("![Benchmark chart]({})", config.link(CHART_PATH)));
+    }
+    lines.push(config.marker.clone());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    config: &TableConfig,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    metadata: Option<&Metadata>,
+) -> Result<(), Error> {
+    let table = construct_table(config, timings, total_millis, metadata);
+    readme::replace_table(s, &config.marker, &table)
 }
 
 pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    let config = TableConfig::from_env();
+    let metadata = config.metadata.then(Metadata::collect);
+    let mut readme = String::from_utf8_lossy(&fs::read(&config.path)?).to_string();
+    update_content(
+        &mut readme,
+        &config,
+        timings,
+        total_millis,
+        metadata.as_ref(),
+    )?;
+    fs::write(&config.path, &readme)?;
+    write_chart(&config, &readme)
+}
+
+/// Replaces the row of a single day in the existing table and recomputes the total.
+fn update_day_content(
+    s: &mut String,
+    config: &TableConfig,
+    timings: Timings,
+    metadata: Option<&Metadata>,
+) -> Result<(), Error> {
+    let mut all_timings = parse_table(readme::read_table(s, &config.marker)?);
+
+    match all_timings.iter_mut().find(|t| t.day == timings.day) {
+        Some(existing) => *existing = timings,
+        None => {
+            all_timings.push(timings);
+            all_timings.sort_by_key(|t| t.day);
+        }
+    }
+
+    let total_millis = all_timings.iter().map(|t| t.total_nanos).sum::<f64>() / 1_000_000_f64;
+    update_content(s, config, all_timings, total_millis, metadata)
+}
+
+pub fn update_day(timings: Timings) -> Result<(), Error> {
+    let config = TableConfig::from_env();
+    let metadata = config.metadata.then(Metadata::collect);
+    let mut readme = String::from_utf8_lossy(&fs::read(&config.path)?).to_string();
+    update_day_content(&mut readme, &config, timings, metadata.as_ref())?;
+    fs::write(&config.path, &readme)?;
+    write_chart(&config, &readme)
+}
+
+/// Renders the chart embedded below the table from the timings in the updated file.
+fn write_chart(config: &TableConfig, readme: &str) -> Result<(), Error> {
+    let timings = parse_table(readme::read_table(readme, &config.marker)?);
+    if let Some(svg) = benchmark_chart::render(&timings) {
+        fs::write(CHART_PATH, svg)?;
+    }
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use super::{
+        parse_cpu_model, parse_nanos, parse_table, update_content, update_day_content, Metadata,
+        TableConfig, Timings, MARKER,
+    };
     use crate::day;
 
     fn get_mock_timings() -> Vec<Timings> {
@@ -134,28 +351,71 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
+    #[test]
+    fn leaves_out_the_chart_without_timings() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &TableConfig::default(), vec![], 0.0, None).unwrap();
+        assert!(!s.contains("![Benchmark chart]"));
+        assert!(s.ends_with(&format!("**Total: 0.00ms**\n{MARKER}")));
+    }
+
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +423,14 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -177,10 +444,143 @@ mod tests {
             "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
+            "",
+            "![Benchmark chart](./.assets/benchmarks.svg)",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn leaves_part_two_of_last_day_empty() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let timings = vec![Timings {
+            day: day!(25),
+            part_1: Some("10ms".into()),
+            part_2: None,
+            total_nanos: 1e+7,
+        }];
+        update_content(&mut s, &TableConfig::default(), timings, 10.0, None).unwrap();
+        assert!(s.contains("| [Day 25](./src/bin/25.rs) | `10ms` |  |"));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_nanos("74.13ns"), Some(74.13));
+        assert_eq!(parse_nanos("2.5µs"), Some(2500.0));
+        assert_eq!(parse_nanos("10ms"), Some(10_000_000.0));
+        assert_eq!(parse_nanos("2s"), Some(2_000_000_000.0));
+        assert_eq!(parse_nanos("-"), None);
+    }
+
+    #[test]
+    fn parses_existing_table() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings.push(Timings {
+            day: day!(25),
+            part_1: Some("5ms".into()),
+            part_2: None,
+            total_nanos: 5e+6,
+        });
+        update_content(&mut s, &TableConfig::default(), timings, 190.0, None).unwrap();
+
+        let parsed = parse_table(&s);
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[0].day, day!(1));
+        assert_eq!(parsed[0].part_1.as_deref(), Some("10ms"));
+        assert_eq!(parsed[0].part_2.as_deref(), Some("20ms"));
+        assert_eq!(parsed[0].total_nanos, 3e+7);
+        assert_eq!(parsed[3].day, day!(25));
+        assert_eq!(parsed[3].part_2, None);
+    }
+
+    #[test]
+    fn updates_single_day() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+
+        let timings = Timings {
+            day: day!(2),
+            part_1: Some("1ms".into()),
+            part_2: Some("-".into()),
+            total_nanos: 1e+6,
+        };
+        update_day_content(&mut s, &TableConfig::default(), timings, None).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `1ms` | `-` |"));
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |"));
+        assert!(s.contains("**Total: 121.00ms**"));
+
+        let timings = Timings {
+            day: day!(3),
+            part_1: Some("4ms".into()),
+            part_2: Some("5ms".into()),
+            total_nanos: 9e+6,
+        };
+        update_day_content(&mut s, &TableConfig::default(), timings, None).unwrap();
+        let rows: Vec<&str> = s.lines().filter(|l| l.starts_with("| [Day")).collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows[2].starts_with("| [Day 3]"));
+        assert!(s.contains("**Total: 130.00ms**"));
+    }
+
+    #[test]
+    fn uses_configured_layout() {
+        let config = TableConfig {
+            path: "docs/benchmarks.md".into(),
+            marker: "<!--- timings --->".into(),
+            heading_level: 3,
+            metadata: false,
+        };
+        let mut s = "foo\n<!--- timings --->\n<!--- timings --->\nbaz".to_string();
+        update_content(&mut s, &config, get_mock_timings(), 190.0, None).unwrap();
+        assert!(s.starts_with("foo\n<!--- timings --->\n### Benchmarks\n"));
+        assert!(s.contains("| [Day 1](../src/bin/01.rs) | `10ms` | `20ms` |"));
+        assert!(
+            s.contains("![Benchmark chart](../.assets/benchmarks.svg)\n<!--- timings --->\nbaz")
+        );
+        assert!(!s.contains(MARKER));
+    }
+
+    #[test]
+    fn includes_metadata() {
+        let metadata = Metadata {
+            rustc: Some("rustc 1.75.0".into()),
+            cpu: Some("AMD Ryzen 7 5800X 8-Core Processor".into()),
+            profile: Some("release".into()),
+            date: Some("2023-12-05".into()),
+            commit: None,
+        };
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let config = TableConfig::default();
+        update_content(&mut s, &config, get_mock_timings(), 190.0, Some(&metadata)).unwrap();
+        assert!(s.contains("**Total: 190.00ms**\n\n<sub>rustc 1.75.0 · AMD Ryzen 7 5800X 8-Core Processor · release profile · 2023-12-05</sub>\n"));
+
+        // the metadata line is not mistaken for a row when the table is read back.
+        assert_eq!(parse_table(&s).len(), 3);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let empty = Metadata::default();
+        update_content(&mut s, &config, get_mock_timings(), 190.0, Some(&empty)).unwrap();
+        assert!(!s.contains("<sub>"));
+    }
+
+    #[test]
+    fn parses_cpu_model() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz\n\nprocessor\t: 1\nmodel name\t: Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz\n";
+        assert_eq!(
+            parse_cpu_model(cpuinfo).as_deref(),
+            Some("Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz")
+        );
+        assert_eq!(parse_cpu_model("processor\t: 0\n"), None);
+    }
 }
@@ -0,0 +1,180 @@
+/// Module that updates the readme with a table of collected stars.
+/// The table mirrors the one generated by the `advent-readme-stars` Github action.
+use std::fs;
+
+use crate::template::leaderboard::Member;
+use crate::template::readme;
+use crate::template::submissions::{self, Progress, Submission};
+use crate::{all_days, Day, PuzzleId, Year};
+
+pub use crate::template::readme::Error;
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+static STAR_SYMBOL: &str = "⭐";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub progress: Progress,
+}
+
+/// Merges the stars recorded in the submission history with the stars of a leaderboard member.
+/// Days without any star are omitted.
+#[must_use]
+pub fn collect(year: Year, submissions: &[Submission], member: Option<&Member>) -> Vec<Stars> {
+    all_days()
+        .filter_map(|day| {
+            let local = submissions::progress(submissions, PuzzleId::new(year, day));
+            let remote = member
+                .and_then(|m| m.completion_for(day))
+                .map(|c| Progress {
+                    part_1: c.part_1.is_some(),
+                    part_2: c.part_2.is_some(),
+                })
+                .unwrap_or_default();
+
+            let progress = Progress {
+                part_1: local.part_1 || remote.part_1,
+                part_2: local.part_2 || remote.part_2,
+            };
+
+            (progress.part_1 || progress.part_2).then_some(Stars { day, progress })
+        })
+        .collect()
+}
+
+fn construct_table(prefix: &str, year: Year, stars: Vec<Stars>) -> String {
+    let header = format!("{prefix} {year} Results");
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let symbol = |collected: bool| if collected { STAR_SYMBOL } else { "" };
+
+    for star in stars {
+        let day = star.day.into_inner();
+        lines.push(format!(
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+            symbol(star.progress.part_1),
+            symbol(star.progress.part_2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, stars: Vec<Stars>) -> Result<(), Error> {
+    let table = construct_table("##", year, stars);
+    readme::replace_table(s, MARKER, &table)
+}
+
+pub fn update(year: Year, stars: Vec<Stars>) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, stars)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collect, update_content, MARKER};
+    use crate::template::leaderboard::{Completion, Member};
+    use crate::template::submissions::{Outcome, Submission};
+    use crate::{day, year, Part};
+
+    fn get_mock_submissions() -> Vec<Submission> {
+        vec![
+            Submission {
+                year: year!(2023),
+                day: day!(1),
+                part: Part::One,
+                answer: "142".into(),
+                outcome: Outcome::Correct,
+                timestamp: 1_701_407_112,
+            },
+            Submission {
+                year: year!(2023),
+                day: day!(3),
+                part: Part::One,
+                answer: "4361".into(),
+                outcome: Outcome::Incorrect,
+                timestamp: 1_701_580_000,
+            },
+        ]
+    }
+
+    fn get_mock_member() -> Member {
+        Member {
+            id: 1000,
+            name: Some("Ed".into()),
+            stars: 3,
+            local_score: 10,
+            last_star_ts: None,
+            completion: vec![
+                Completion {
+                    day: day!(1),
+                    part_1: Some(1_701_407_112),
+                    part_2: Some(1_701_407_500),
+                },
+                Completion {
+                    day: day!(2),
+                    part_1: Some(1_701_494_000),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn merges_history_and_leaderboard() {
+        let stars = collect(
+            year!(2023),
+            &get_mock_submissions(),
+            Some(&get_mock_member()),
+        );
+        assert_eq!(stars.len(), 2);
+        assert!(stars[0].progress.part_1 && stars[0].progress.part_2);
+        assert!(stars[1].progress.part_1 && !stars[1].progress.part_2);
+    }
+
+    #[test]
+    fn uses_history_without_leaderboard() {
+        let stars = collect(year!(2023), &get_mock_submissions(), None);
+        assert_eq!(stars.len(), 1);
+        assert_eq!(stars[0].day, day!(1));
+        assert!(stars[0].progress.part_1 && !stars[0].progress.part_2);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        let stars = collect(
+            year!(2023),
+            &get_mock_submissions(),
+            Some(&get_mock_member()),
+        );
+        update_content(&mut s, year!(2023), stars).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2023/day/2) | ⭐ |  |",
+            "<!--- advent_readme_stars table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
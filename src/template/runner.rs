@@ -1,6 +1,7 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::commands::session;
+use crate::template::{aoc_cli, paths, submissions, ANSI_ITALIC, ANSI_RESET};
+use crate::{Part, PuzzleId};
 use std::fmt::Display;
 use std::io::{stdout, Write};
 use std::process::Output;
@@ -9,7 +10,12 @@ use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: impl Into<PuzzleId>,
+    part: Part,
+) {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
@@ -18,7 +24,7 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, d
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, puzzle.into(), part);
     }
 }
 
@@ -132,8 +138,8 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
-    part: u8,
+    puzzle: PuzzleId,
+    part: Part,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
     let args: Vec<String> = env::args().collect();
 
@@ -148,11 +154,23 @@ fn submit_result<T: Display>(
 
     let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let part_submit = match args.get(part_index).map(|arg| arg.parse::<Part>()) {
+        Some(Ok(part)) => part,
+        Some(Err(e)) => {
+            eprintln!("Unexpected command-line input: {e}. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
     };
 
+    if part_submit == Part::Two && !puzzle.day.has_part_two() {
+        eprintln!("Day {} only has a single part.", puzzle.day);
+        process::exit(1);
+    }
+
     if part_submit != part {
         return None;
     }
@@ -162,6 +180,43 @@ fn submit_result<T: Display>(
         process::exit(1);
     }
 
+    session::require_valid(puzzle.year);
+
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let output = aoc_cli::submit(puzzle, part, &answer);
+
+    if let Ok(output) = &output {
+        let outcome = submissions::Outcome::from_response(&String::from_utf8_lossy(&output.stdout));
+
+        record_submission(puzzle, part, &answer, outcome);
+
+        if part == Part::One
+            && puzzle.day.has_part_two()
+            && outcome == submissions::Outcome::Correct
+        {
+            refresh_puzzle(puzzle);
+        }
+    }
+
+    Some(output)
+}
+
+/// Store the outcome of a submission in the local submission history.
+fn record_submission(puzzle: PuzzleId, part: Part, answer: &str, outcome: submissions::Outcome) {
+    if let Err(e) = submissions::record(puzzle, part, answer, outcome) {
+        eprintln!("Failed to record submission: {e}");
+    }
+}
+
+/// Part two only becomes visible after solving part one, so fetch the description again.
+fn refresh_puzzle(puzzle: PuzzleId) {
+    println!("Refreshing puzzle description with part two...");
+    match aoc_cli::download_puzzle(puzzle) {
+        Ok(_) => println!(
+            "🎄 Part two is waiting in \"{}\".",
+            paths::get_puzzle_path(puzzle)
+        ),
+        Err(e) => eprintln!("Failed to refresh puzzle description: {e}"),
+    }
 }
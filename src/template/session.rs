@@ -0,0 +1,229 @@
+/// Module that locates, stores and validates the session cookie.
+/// The cookie is looked up in the same places as aoc-cli does, plus a project-local file.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::template::aoc_web::{self, AocWebError};
+use crate::Year;
+
+pub const ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+pub const FILE_NAME: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound,
+    Expired,
+    Web(AocWebError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound => write!(
+                f,
+                "no session cookie found. Run `cargo session set <cookie>` to store one."
+            ),
+            Error::Expired => write!(
+                f,
+                "the session cookie is not logged in, it probably expired. Run `cargo session set <cookie>` with a fresh one."
+            ),
+            Error::Web(e) => write!(f, "could not validate the session cookie: {e}"),
+        }
+    }
+}
+
+/// Where a session cookie was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    Project(PathBuf),
+    Home(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Env => write!(f, "environment variable `{ENV_VAR}`"),
+            Source::Project(path) | Source::Home(path) => write!(f, "\"{}\"", path.display()),
+        }
+    }
+}
+
+impl Source {
+    /// The session file to hand to aoc-cli, if aoc-cli would not find the cookie by itself.
+    #[must_use]
+    pub fn session_file(&self) -> Option<&Path> {
+        match self {
+            Source::Project(path) => Some(path),
+            Source::Env | Source::Home(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub cookie: String,
+    pub source: Source,
+}
+
+/// The account a session cookie belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    pub name: String,
+    pub stars: Option<u32>,
+}
+
+#[must_use]
+pub fn project_path() -> PathBuf {
+    env::current_dir().unwrap().join(FILE_NAME)
+}
+
+#[must_use]
+pub fn home_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(FILE_NAME))
+}
+
+/// Finds the session cookie. The environment variable takes precedence over the project-local file,
+/// which in turn takes precedence over the file in the home directory.
+#[must_use]
+pub fn find() -> Option<Session> {
+    if let Ok(cookie) = env::var(ENV_VAR) {
+        if !cookie.trim().is_empty() {
+            return Some(Session {
+                cookie: cookie.trim().to_string(),
+                source: Source::Env,
+            });
+        }
+    }
+
+    let read = |path: &Path| {
+        let cookie = fs::read_to_string(path).ok()?.trim().to_string();
+        (!cookie.is_empty()).then_some(cookie)
+    };
+
+    let project = project_path();
+    if let Some(cookie) = read(&project) {
+        return Some(Session {
+            cookie,
+            source: Source::Project(project),
+        });
+    }
+
+    let home = home_path()?;
+    read(&home).map(|cookie| Session {
+        cookie,
+        source: Source::Home(home),
+    })
+}
+
+/// Writes the cookie to the project-local session file, or to the one in the home directory if `global` is set.
+pub fn store(cookie: &str, global: bool) -> io::Result<PathBuf> {
+    let path = if global {
+        home_path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?
+    } else {
+        project_path()
+    };
+    fs::write(&path, format!("{}\n", normalize(cookie)))?;
+    Ok(path)
+}
+
+/// Accepts both the raw cookie value and a `session=<value>` pair copied from the browser.
+fn normalize(cookie: &str) -> &str {
+    let cookie = cookie.trim();
+    cookie.strip_prefix("session=").unwrap_or(cookie)
+}
+
+/// Hides all but the first and last four characters of the cookie.
+#[must_use]
+pub fn mask(cookie: &str) -> String {
+    let chars: Vec<char> = cookie.chars().collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+    let start: String = chars[..4].iter().collect();
+    let end: String = chars[chars.len() - 4..].iter().collect();
+    format!("{start}…{end} ({} characters)", chars.len())
+}
+
+/// Extracts the logged in user from the header of an event page.
+/// Logged out pages show a login link instead of the user name.
+#[must_use]
+pub fn parse_user(html: &str) -> Option<User> {
+    let re = Regex::new(r#"(?s)<div class="user">(?<name>[^<]*)(?<rest>.*?)</div>"#).unwrap();
+    let caps = re.captures(html)?;
+
+    let name = caps["name"].trim().to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let stars = Regex::new(r#"class="star-count">(?<stars>\d+)\*"#)
+        .unwrap()
+        .captures(&caps["rest"])
+        .and_then(|c| c["stars"].parse().ok());
+
+    Some(User { name, stars })
+}
+
+/// Validates the session cookie against the event page of `year` and returns the logged in user.
+pub fn check(year: Year) -> Result<User, Error> {
+    if find().is_none() {
+        return Err(Error::NotFound);
+    }
+    let html = aoc_web::get(&year.to_string()).map_err(Error::Web)?;
+    parse_user(&html).ok_or(Error::Expired)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{mask, normalize, parse_user, User};
+
+    #[test]
+    fn parses_logged_in_user() {
+        let html = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2023/about">[About]</a></li></ul></nav><div class="user">Ed Chapman <a href="/2023/support" class="supporter-badge" title="Advent of Code Supporter">AoC++</a> <span class="star-count">12*</span></div></div></header>"#;
+        assert_eq!(
+            parse_user(html),
+            Some(User {
+                name: "Ed Chapman".into(),
+                stars: Some(12)
+            })
+        );
+    }
+
+    #[test]
+    fn parses_user_without_stars() {
+        let html = r#"<div class="user">(anonymous user #3031)</div>"#;
+        assert_eq!(
+            parse_user(html),
+            Some(User {
+                name: "(anonymous user #3031)".into(),
+                stars: None
+            })
+        );
+    }
+
+    #[test]
+    fn detects_logged_out_page() {
+        let html = r#"<nav><ul><li><a href="/2023/auth/login">[Log In]</a></li></ul></nav>"#;
+        assert_eq!(parse_user(html), None);
+    }
+
+    #[test]
+    fn masks_cookie() {
+        assert_eq!(mask("53616c7465645f5f"), "5361…5f5f (16 characters)");
+        assert_eq!(mask("short"), "*****");
+    }
+
+    #[test]
+    fn normalizes_cookie() {
+        assert_eq!(normalize(" session=abc\n"), "abc");
+        assert_eq!(normalize("abc"), "abc");
+    }
+}
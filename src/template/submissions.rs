@@ -0,0 +1,280 @@
+/// Module that keeps a local history of submitted answers in `data/submissions.json`.
+use std::{fmt::Display, fs, io, path::Path};
+
+use serde_json::{json, Value};
+
+use crate::template::datetime;
+use crate::{Day, Part, PuzzleId, Year};
+
+const HISTORY_PATH: &str = "data/submissions.json";
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid submission history: {e}"),
+            Error::Parser(reason) => write!(f, "unexpected submission history format: {reason}"),
+            Error::IO(e) => write!(f, "could not access submission history: {e}"),
+        }
+    }
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooSoon,
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    /// Derives the outcome from the text the website (and thus aoc-cli) responds with.
+    #[must_use]
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if response.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooSoon => "too_soon",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_label(s: &str) -> Self {
+        match s {
+            "correct" => Outcome::Correct,
+            "incorrect" => Outcome::Incorrect,
+            "too_soon" => Outcome::TooSoon,
+            "wrong_level" => Outcome::WrongLevel,
+            _ => Outcome::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub timestamp: i64,
+}
+
+impl Submission {
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year.into_inner(),
+            "day": self.day.into_inner(),
+            "part": self.part.into_inner(),
+            "answer": self.answer,
+            "outcome": self.outcome.label(),
+            "timestamp": self.timestamp,
+        })
+    }
+
+    fn from_json(value: &Value) -> Result<Self, Error> {
+        let field = |name: &str| {
+            value[name]
+                .as_u64()
+                .ok_or_else(|| Error::Parser(format!("submission without \"{name}\".")))
+        };
+
+        let day = u8::try_from(field("day")?)
+            .ok()
+            .and_then(Day::new)
+            .ok_or_else(|| Error::Parser("invalid day.".into()))?;
+
+        let year = u16::try_from(field("year")?)
+            .ok()
+            .and_then(Year::new)
+            .ok_or_else(|| Error::Parser("invalid year.".into()))?;
+
+        let part = u8::try_from(field("part")?)
+            .ok()
+            .and_then(Part::new)
+            .ok_or_else(|| Error::Parser("invalid part.".into()))?;
+
+        Ok(Submission {
+            year,
+            day,
+            part,
+            answer: value["answer"].as_str().unwrap_or_default().to_string(),
+            outcome: Outcome::from_label(value["outcome"].as_str().unwrap_or_default()),
+            timestamp: value["timestamp"].as_i64().unwrap_or_default(),
+        })
+    }
+}
+
+/// Which stars of a day have been collected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+pub fn parse(json: &str) -> Result<Vec<Submission>, Error> {
+    let value: Value = serde_json::from_str(json)?;
+    value
+        .as_array()
+        .ok_or_else(|| Error::Parser("expected a list of submissions.".into()))?
+        .iter()
+        .map(Submission::from_json)
+        .collect()
+}
+
+#[must_use]
+pub fn serialize(submissions: &[Submission]) -> String {
+    let values: Vec<Value> = submissions.iter().map(Submission::to_json).collect();
+    // serializing a `Value` cannot fail.
+    serde_json::to_string_pretty(&values).unwrap() + "\n"
+}
+
+/// Reads the submission history, which is empty if nothing was submitted yet.
+pub fn read() -> Result<Vec<Submission>, Error> {
+    if !Path::new(HISTORY_PATH).exists() {
+        return Ok(vec![]);
+    }
+    parse(&fs::read_to_string(HISTORY_PATH)?)
+}
+
+/// Appends a submission to the history.
+pub fn record(puzzle: PuzzleId, part: Part, answer: &str, outcome: Outcome) -> Result<(), Error> {
+    let mut submissions = read()?;
+    submissions.push(Submission {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        timestamp: datetime::now(),
+    });
+    fs::write(HISTORY_PATH, serialize(&submissions))?;
+    Ok(())
+}
+
+/// Returns the stars of `puzzle` according to the correct submissions in the history.
+#[must_use]
+pub fn progress(submissions: &[Submission], puzzle: PuzzleId) -> Progress {
+    let solved = |part: Part| {
+        submissions.iter().any(|s| {
+            s.year == puzzle.year
+                && s.day == puzzle.day
+                && s.part == part
+                && s.outcome == Outcome::Correct
+        })
+    };
+
+    Progress {
+        part_1: solved(Part::One),
+        part_2: solved(Part::Two),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, progress, serialize, Outcome, Progress, Submission};
+    use crate::{day, year, Part, PuzzleId};
+
+    fn get_mock_submissions() -> Vec<Submission> {
+        vec![
+            Submission {
+                year: year!(2023),
+                day: day!(1),
+                part: Part::One,
+                answer: "142".into(),
+                outcome: Outcome::Correct,
+                timestamp: 1_701_407_112,
+            },
+            Submission {
+                year: year!(2023),
+                day: day!(1),
+                part: Part::Two,
+                answer: "280".into(),
+                outcome: Outcome::Incorrect,
+                timestamp: 1_701_407_200,
+            },
+            Submission {
+                year: year!(2022),
+                day: day!(1),
+                part: Part::Two,
+                answer: "281".into(),
+                outcome: Outcome::Correct,
+                timestamp: 1_701_407_300,
+            },
+        ]
+    }
+
+    #[test]
+    fn detects_outcomes() {
+        assert_eq!(
+            Outcome::from_response("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer; your answer is too high."),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::from_response("You gave an answer too recently; you have 30s left to wait."),
+            Outcome::TooSoon
+        );
+        assert_eq!(Outcome::from_response(""), Outcome::Unknown);
+    }
+
+    #[test]
+    fn round_trips_history() {
+        let submissions = get_mock_submissions();
+        assert_eq!(parse(&serialize(&submissions)).unwrap(), submissions);
+    }
+
+    #[test]
+    fn derives_progress_from_correct_submissions() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            progress(&submissions, PuzzleId::new(year!(2023), day!(1))),
+            Progress {
+                part_1: true,
+                part_2: false
+            }
+        );
+        assert_eq!(
+            progress(&submissions, PuzzleId::new(year!(2023), day!(2))),
+            Progress::default()
+        );
+    }
+}
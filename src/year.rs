@@ -0,0 +1,204 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::datetime;
+use crate::Day;
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an event from 2015 up to the current year, which may not have started yet).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if an event took place or is still to come that year,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > Self::current().0 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the current calendar year, whose event may not have started yet.
+    pub fn current() -> Self {
+        let (year, _, _) =
+            datetime::civil_from_days(datetime::now().div_euclid(datetime::SECONDS_PER_DAY));
+        Self(u16::try_from(year).unwrap())
+    }
+
+    /// Returns the year of the most recent event that has started, i.e. whose first puzzle is unlocked.
+    pub fn latest() -> Self {
+        let year = Self::current();
+
+        if Day::__new_unchecked(1).is_unlocked(year) {
+            year
+        } else {
+            Self(year.0 - 1)
+        }
+    }
+
+    /// Returns the year configured via the `AOC_YEAR` environment variable,
+    /// falling back to the most recent event if it is not set.
+    ///
+    /// # Panics
+    /// Panics if `AOC_YEAR` is set but is not a valid year, see [`Year::try_configured`].
+    pub fn configured() -> Self {
+        Self::try_configured().unwrap_or_else(|e| panic!("invalid AOC_YEAR: {e}"))
+    }
+
+    /// Returns the year configured via the `AOC_YEAR` environment variable,
+    /// falling back to the most recent event if it is not set.
+    pub fn try_configured() -> Result<Self, YearFromStrError> {
+        match env::var("AOC_YEAR") {
+            Ok(year) => year.trim().parse(),
+            Err(env::VarError::NotPresent) => Ok(Self::latest()),
+            Err(env::VarError::NotUnicode(_)) => Err(YearFromStrError),
+        }
+    }
+
+    /// Returns `true` if this is the [configured](Year::configured) year.
+    /// Puzzles of the configured year use the flat file layout of the template.
+    pub fn is_configured(self) -> bool {
+        self == Self::configured()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a year between {FIRST_YEAR} and {}",
+            Year::current()
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by its year and day.
+///
+/// A [`Day`] converts into the puzzle of that day in the [configured](Year::configured) year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(Year::configured(), day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}, {}", self.day, self.year)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!("invalid year `", $year, "`, expecting a value from 2015 on"),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn validates_years() {
+        assert!(Year::new(2014).is_none());
+        assert!(Year::new(2015).is_some());
+        assert!(Year::new(2023).is_some());
+        assert!(Year::new(9999).is_none());
+        assert!(Year::latest().into_inner() >= 2023);
+        // the event of the current year can be set up before it starts.
+        assert!(Year::new(Year::current().into_inner()).is_some());
+        assert!(Year::new(Year::current().into_inner() + 1).is_none());
+        assert!(Year::current() >= Year::latest());
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert!("23".parse::<Year>().is_err());
+        assert!("year".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn displays_puzzle_ids() {
+        let puzzle = PuzzleId::new(Year(2022), day!(5));
+        assert_eq!(puzzle.to_string(), "day 05, 2022");
+    }
+}